@@ -7,7 +7,6 @@ fn main() {
     let dest = PathBuf::from(&env::var("OUT_DIR").unwrap());
     let mut file_gl_and_gles =
         File::create(&Path::new(&dest).join("gl_and_gles_bindings.rs")).unwrap();
-    let mut file_gl = File::create(&Path::new(&dest).join("gl_bindings.rs")).unwrap();
     let mut file_gles = File::create(&Path::new(&dest).join("gles_bindings.rs")).unwrap();
 
     // OpenGL 3.3 bindings
@@ -19,6 +18,30 @@ fn main() {
         "GL_ARB_transform_feedback2",
         "GL_ARB_internalformat_query",
         "GL_ARB_invalidate_subdata",
+        "GL_EXT_depth_bounds_test",
+        "GL_ARB_multi_bind",
+        "GL_ARB_get_program_binary",
+        "GL_ARB_tessellation_shader",
+        "GL_ARB_program_interface_query",
+        "GL_ARB_robustness",
+        "GL_ARB_separate_shader_objects",
+        "GL_ARB_vertex_attrib_binding",
+        "GL_ARB_draw_indirect",
+        "GL_ARB_draw_buffers_blend",
+        "GL_ARB_internalformat_query2",
+        "GL_KHR_debug",
+        "GL_OVR_multiview",
+        "GL_OVR_multiview2",
+        "GL_KHR_robustness",
+        "GL_ARB_framebuffer_no_attachments",
+        "GL_ARB_copy_image",
+        "GL_ARB_compute_shader",
+        "GL_ARB_shader_image_load_store",
+        "GL_ARB_texture_barrier",
+        "GL_NV_texture_barrier",
+        "GL_ARB_sample_shading",
+        "GL_ARB_ES2_compatibility",
+        "GL_ARB_buffer_storage",
     ];
     let gl_reg = Registry::new(
         Api::Gl,
@@ -27,20 +50,37 @@ fn main() {
         Fallbacks::All,
         gl_extensions,
     );
-    gl_reg
-        .write_bindings(gl_generator::StructGenerator, &mut file_gl)
-        .unwrap();
+    // The desktop registry is still needed for the combined enums below.
+    if env::var_os("CARGO_FEATURE_GLES_ONLY").is_none() {
+        let mut file_gl = File::create(&Path::new(&dest).join("gl_bindings.rs")).unwrap();
+        gl_reg
+            .write_bindings(gl_generator::StructGenerator, &mut file_gl)
+            .unwrap();
+    }
 
-    // GLES 3.0 bindings
+    // GLES 3.2 bindings
     let gles_extensions = [
         "GL_EXT_disjoint_timer_query",
         "GL_EXT_texture_filter_anisotropic",
         "GL_OES_texture_half_float",
         "GL_OES_vertex_array_object",
+        "GL_OES_draw_elements_base_vertex",
+        "GL_EXT_multi_draw_arrays",
+        "GL_OES_draw_buffers_indexed",
+        "GL_OES_EGL_image",
+        "GL_OES_EGL_image_external",
+        "GL_OVR_multiview",
+        "GL_OVR_multiview2",
+        "GL_KHR_robustness",
+        "GL_EXT_multisampled_render_to_texture",
+        "GL_EXT_copy_image",
+        "GL_OES_sample_shading",
+        "GL_EXT_texture_border_clamp",
+        "GL_EXT_buffer_storage",
     ];
     let gles_reg = Registry::new(
         Api::Gles2,
-        (3, 0),
+        (3, 2),
         Profile::Core,
         Fallbacks::All,
         gles_extensions,
@@ -49,7 +89,7 @@ fn main() {
         .write_bindings(gl_generator::StructGenerator, &mut file_gles)
         .unwrap();
 
-    // OpenGL 3.3 + GLES 3.0 bindings. Used to get all enums
+    // OpenGL 3.3 + GLES 3.2 bindings. Used to get all enums
     let gl_reg = gl_reg + gles_reg;
     gl_reg
         .write_bindings(gl_generator::StructGenerator, &mut file_gl_and_gles)
@@ -3,13 +3,23 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
 pub mod gl {
+    // With the desktop arms compiled out, the arguments of desktop-only
+    // methods are only passed along by forward_debug!.
+    #![cfg_attr(feature = "gles-only", allow(clippy::only_used_in_recursion))]
+
     pub use self::ffi::types::*;
     pub use self::ffi::*;
+    use std::cell::{Cell, RefCell};
+    use std::collections::{HashMap, HashSet, VecDeque};
+    use std::convert::TryFrom;
     use std::ffi::{CStr, CString};
+    use std::fmt;
     use std::mem::size_of;
+    use std::ops::{Deref, DerefMut};
     use std::os::raw::{c_char, c_int, c_void};
     use std::ptr;
     use std::rc::Rc;
+    use std::slice;
     use std::str;
 
     #[derive(Copy, Clone, Debug, PartialEq)]
@@ -19,8 +29,175 @@ pub mod gl {
     }
 
     pub enum Gl {
-        Gl(self::ffi_gl::Gl),
-        Gles(self::ffi_gles::Gles2),
+        #[cfg(not(feature = "gles-only"))]
+        Gl(ContextFns<self::ffi_gl::Gl>),
+        Gles(ContextFns<self::ffi_gles::Gles2>),
+        /// Records calls into a log instead of making them; see `GlCall`.
+        Recording(RefCell<Vec<GlCall>>),
+        /// Makes no calls at all, for testing code without a context.
+        Mock(MockState),
+        /// Forwards calls to another context and checks for errors after
+        /// each one, panicking if the flag is set and logging them otherwise.
+        Debug(Rc<Gl>, bool),
+    }
+
+    /// Only names the backend, so that formatting a `Gl` never makes calls
+    /// into the driver.
+    impl fmt::Debug for Gl {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(..) => f.write_str("Gl"),
+                Gl::Gles(..) => f.write_str("Gles"),
+                Gl::Recording(..) => f.write_str("Recording"),
+                Gl::Mock(..) => f.write_str("Mock"),
+                Gl::Debug(inner, panic_on_error) => f
+                    .debug_tuple("Debug")
+                    .field(inner)
+                    .field(panic_on_error)
+                    .finish(),
+            }
+        }
+    }
+
+    /// Unregisters the debug callback and deletes the objects created for a
+    /// real context, which should therefore still be current when its `Gl`
+    /// is dropped.
+    impl Drop for Gl {
+        fn drop(&mut self) {
+            let state = match &*self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => &gl.state,
+                Gl::Gles(gles) => &gles.state,
+                _ => return,
+            };
+            // The driver holds a pointer to the callback until it is told to
+            // stop using it.
+            if let Some(callback) = state.debug_callback.take() {
+                self.remove_debug_message_callback();
+                drop(callback);
+            }
+            let vertex_array = state.attributeless_vertex_array.get();
+            if vertex_array != 0 {
+                self.delete_vertex_arrays(&[vertex_array]);
+            }
+        }
+    }
+
+    /// Forwards a method call on a `Gl::Debug` to the context it wraps and
+    /// checks for errors afterwards, returning from the calling method.
+    macro_rules! forward_debug {
+        ($self:ident.$method:ident($($arg:expr),*)) => {
+            if let Gl::Debug(inner, panic_on_error) = $self {
+                let result = inner.$method($($arg),*);
+                inner.check_debug_error(stringify!($method), *panic_on_error);
+                return result;
+            }
+        };
+    }
+
+    /// Evaluates `$body` and, in debug builds, then panics if any GL errors
+    /// are pending on `$gl`, naming `$label` as their source. Errors raised
+    /// before the scope are blamed on it too, so start from a clean slate.
+    ///
+    /// ```ignore
+    /// let texture = gl_checked!(gl, "upload", upload_texture(&gl));
+    /// ```
+    #[macro_export]
+    macro_rules! gl_checked {
+        ($gl:expr, $label:expr, $body:expr) => {{
+            #[cfg(debug_assertions)]
+            let gl = &$gl;
+            let result = $body;
+            #[cfg(debug_assertions)]
+            gl.assert_no_error($label);
+            result
+        }};
+    }
+
+    /// The state behind a mock `Gl`. Object names are handed out in
+    /// increasing order starting from 1, and queries return zeroed or empty
+    /// results, except for strings set with `Gl::set_mock_string` and errors
+    /// queued with `Gl::push_mock_error`.
+    #[derive(Debug, Default)]
+    pub struct MockState {
+        last_id: Cell<GLuint>,
+        strings: RefCell<HashMap<GLenum, String>>,
+        errors: RefCell<VecDeque<GLenum>>,
+    }
+
+    impl MockState {
+        fn next_id(&self) -> GLuint {
+            let id = self.last_id.get() + 1;
+            self.last_id.set(id);
+            id
+        }
+    }
+
+    /// The entry points loaded for a real context, along with the state kept
+    /// for it. Dereferences to the generated bindings, so the variants of `Gl`
+    /// can be matched and called through as `Gl::Gl(gl) => gl.Flush()`.
+    pub struct ContextFns<T> {
+        fns: T,
+        state: ContextState,
+    }
+
+    impl<T> From<T> for ContextFns<T> {
+        fn from(fns: T) -> ContextFns<T> {
+            ContextFns {
+                fns,
+                state: ContextState::default(),
+            }
+        }
+    }
+
+    impl<T> Deref for ContextFns<T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            &self.fns
+        }
+    }
+
+    /// State kept alongside a real context, for objects and query results
+    /// that are cheaper to create or look up once.
+    #[derive(Default)]
+    struct ContextState {
+        attributeless_vertex_array: Cell<GLuint>,
+        core_profile: Cell<Option<bool>>,
+        debug_callback: Cell<Option<Box<DebugCallback>>>,
+        internalformat_query2: Cell<Option<bool>>,
+        max_anisotropy: Cell<Option<GLfloat>>,
+    }
+
+    /// A call made through a recording `Gl`. Entry points without a variant
+    /// of their own are recorded by method name.
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum GlCall {
+        ActiveTexture(GLenum),
+        BindBuffer(GLenum, GLuint),
+        BindFramebuffer(GLenum, GLuint),
+        BindRenderbuffer(GLenum, GLuint),
+        BindTexture(GLenum, GLuint),
+        BindVertexArray(GLuint),
+        BlendEquation(GLenum),
+        BlendFunc(GLenum, GLenum),
+        Clear(GLbitfield),
+        ClearColor(f32, f32, f32, f32),
+        ColorMask(bool, bool, bool, bool),
+        DepthFunc(GLenum),
+        DepthMask(bool),
+        Disable(GLenum),
+        DrawArrays(GLenum, GLint, GLsizei),
+        DrawArraysInstanced(GLenum, GLint, GLsizei, GLsizei),
+        DrawElements(GLenum, GLsizei, GLenum, GLuint),
+        DrawElementsInstanced(GLenum, GLsizei, GLenum, GLuint, GLsizei),
+        Enable(GLenum),
+        PixelStoreI(GLenum, GLint),
+        Scissor(GLint, GLint, GLsizei, GLsizei),
+        UseProgram(GLuint),
+        Viewport(GLint, GLint, GLsizei, GLsizei),
+        Other(&'static str),
     }
 
     pub enum TexImageSource<'a> {
@@ -28,85 +205,589 @@ pub mod gl {
         BufferOffset(i64),
     }
 
+    /// A value returned by `glGetError`.
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub enum GlError {
+        NoError,
+        InvalidEnum,
+        InvalidValue,
+        InvalidOperation,
+        OutOfMemory,
+        InvalidFramebufferOperation,
+        ContextLost,
+        Unknown(GLenum),
+    }
+
+    impl GlError {
+        pub fn from_gl(error: GLenum) -> GlError {
+            match error {
+                ffi::NO_ERROR => GlError::NoError,
+                ffi::INVALID_ENUM => GlError::InvalidEnum,
+                ffi::INVALID_VALUE => GlError::InvalidValue,
+                ffi::INVALID_OPERATION => GlError::InvalidOperation,
+                ffi::OUT_OF_MEMORY => GlError::OutOfMemory,
+                ffi::INVALID_FRAMEBUFFER_OPERATION => GlError::InvalidFramebufferOperation,
+                ffi::CONTEXT_LOST => GlError::ContextLost,
+                error => GlError::Unknown(error),
+            }
+        }
+
+        pub fn to_gl(self) -> GLenum {
+            match self {
+                GlError::NoError => ffi::NO_ERROR,
+                GlError::InvalidEnum => ffi::INVALID_ENUM,
+                GlError::InvalidValue => ffi::INVALID_VALUE,
+                GlError::InvalidOperation => ffi::INVALID_OPERATION,
+                GlError::OutOfMemory => ffi::OUT_OF_MEMORY,
+                GlError::InvalidFramebufferOperation => ffi::INVALID_FRAMEBUFFER_OPERATION,
+                GlError::ContextLost => ffi::CONTEXT_LOST,
+                GlError::Unknown(error) => error,
+            }
+        }
+    }
+
+    /// A pixel format or type that `try_calculate_length` doesn't know the
+    /// size of, or dimensions that are negative or too large to size.
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub enum UnsupportedPixelFormat {
+        Format(GLenum),
+        PixelType(GLenum),
+        Dimensions(GLsizei, GLsizei),
+    }
+
+    /// Why a texture level couldn't be read back by `get_tex_image_into_buffer`
+    /// or `dump_texture`.
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub enum TexImageError {
+        /// GLES reads back through a framebuffer, which only takes 2D and
+        /// cube map face targets.
+        UnsupportedTarget(GLenum),
+        /// An entry point the readback needs isn't loaded, such as
+        /// glGetTexLevelParameteriv before GLES 3.1.
+        MissingEntryPoint(&'static str),
+        PixelFormat(UnsupportedPixelFormat),
+    }
+
+    impl From<UnsupportedPixelFormat> for TexImageError {
+        fn from(error: UnsupportedPixelFormat) -> TexImageError {
+            TexImageError::PixelFormat(error)
+        }
+    }
+
+    /// The vertex of a primitive whose outputs are used for flat-shaded
+    /// varyings.
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub enum ProvokingVertex {
+        First,
+        Last,
+    }
+
+    impl ProvokingVertex {
+        pub fn from_gl(mode: GLenum) -> Option<ProvokingVertex> {
+            match mode {
+                ffi::FIRST_VERTEX_CONVENTION => Some(ProvokingVertex::First),
+                ffi::LAST_VERTEX_CONVENTION => Some(ProvokingVertex::Last),
+                _ => None,
+            }
+        }
+
+        pub fn to_gl(self) -> GLenum {
+            match self {
+                ProvokingVertex::First => ffi::FIRST_VERTEX_CONVENTION,
+                ProvokingVertex::Last => ffi::LAST_VERTEX_CONVENTION,
+            }
+        }
+    }
+
+    /// A binding point for buffer objects, for `bind_buffer_typed`.
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub enum BufferTarget {
+        Array,
+        ElementArray,
+        CopyRead,
+        CopyWrite,
+        PixelPack,
+        PixelUnpack,
+        TransformFeedback,
+        Uniform,
+        DrawIndirect,
+    }
+
+    impl BufferTarget {
+        pub fn from_gl(target: GLenum) -> Option<BufferTarget> {
+            match target {
+                ffi::ARRAY_BUFFER => Some(BufferTarget::Array),
+                ffi::ELEMENT_ARRAY_BUFFER => Some(BufferTarget::ElementArray),
+                ffi::COPY_READ_BUFFER => Some(BufferTarget::CopyRead),
+                ffi::COPY_WRITE_BUFFER => Some(BufferTarget::CopyWrite),
+                ffi::PIXEL_PACK_BUFFER => Some(BufferTarget::PixelPack),
+                ffi::PIXEL_UNPACK_BUFFER => Some(BufferTarget::PixelUnpack),
+                ffi::TRANSFORM_FEEDBACK_BUFFER => Some(BufferTarget::TransformFeedback),
+                ffi::UNIFORM_BUFFER => Some(BufferTarget::Uniform),
+                ffi::DRAW_INDIRECT_BUFFER => Some(BufferTarget::DrawIndirect),
+                _ => None,
+            }
+        }
+
+        pub fn to_gl(self) -> GLenum {
+            match self {
+                BufferTarget::Array => ffi::ARRAY_BUFFER,
+                BufferTarget::ElementArray => ffi::ELEMENT_ARRAY_BUFFER,
+                BufferTarget::CopyRead => ffi::COPY_READ_BUFFER,
+                BufferTarget::CopyWrite => ffi::COPY_WRITE_BUFFER,
+                BufferTarget::PixelPack => ffi::PIXEL_PACK_BUFFER,
+                BufferTarget::PixelUnpack => ffi::PIXEL_UNPACK_BUFFER,
+                BufferTarget::TransformFeedback => ffi::TRANSFORM_FEEDBACK_BUFFER,
+                BufferTarget::Uniform => ffi::UNIFORM_BUFFER,
+                BufferTarget::DrawIndirect => ffi::DRAW_INDIRECT_BUFFER,
+            }
+        }
+    }
+
+    impl From<BufferTarget> for GLenum {
+        fn from(value: BufferTarget) -> GLenum {
+            value.to_gl()
+        }
+    }
+
+    /// A usage hint for a buffer's data store, for `buffer_data_typed`.
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub enum BufferUsage {
+        StreamDraw,
+        StreamRead,
+        StreamCopy,
+        StaticDraw,
+        StaticRead,
+        StaticCopy,
+        DynamicDraw,
+        DynamicRead,
+        DynamicCopy,
+    }
+
+    impl BufferUsage {
+        pub fn from_gl(usage: GLenum) -> Option<BufferUsage> {
+            match usage {
+                ffi::STREAM_DRAW => Some(BufferUsage::StreamDraw),
+                ffi::STREAM_READ => Some(BufferUsage::StreamRead),
+                ffi::STREAM_COPY => Some(BufferUsage::StreamCopy),
+                ffi::STATIC_DRAW => Some(BufferUsage::StaticDraw),
+                ffi::STATIC_READ => Some(BufferUsage::StaticRead),
+                ffi::STATIC_COPY => Some(BufferUsage::StaticCopy),
+                ffi::DYNAMIC_DRAW => Some(BufferUsage::DynamicDraw),
+                ffi::DYNAMIC_READ => Some(BufferUsage::DynamicRead),
+                ffi::DYNAMIC_COPY => Some(BufferUsage::DynamicCopy),
+                _ => None,
+            }
+        }
+
+        pub fn to_gl(self) -> GLenum {
+            match self {
+                BufferUsage::StreamDraw => ffi::STREAM_DRAW,
+                BufferUsage::StreamRead => ffi::STREAM_READ,
+                BufferUsage::StreamCopy => ffi::STREAM_COPY,
+                BufferUsage::StaticDraw => ffi::STATIC_DRAW,
+                BufferUsage::StaticRead => ffi::STATIC_READ,
+                BufferUsage::StaticCopy => ffi::STATIC_COPY,
+                BufferUsage::DynamicDraw => ffi::DYNAMIC_DRAW,
+                BufferUsage::DynamicRead => ffi::DYNAMIC_READ,
+                BufferUsage::DynamicCopy => ffi::DYNAMIC_COPY,
+            }
+        }
+    }
+
+    impl From<BufferUsage> for GLenum {
+        fn from(value: BufferUsage) -> GLenum {
+            value.to_gl()
+        }
+    }
+
+    /// Identification strings for the driver behind a context, as used to
+    /// key driver-specific workarounds.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct GpuInfo {
+        pub renderer: String,
+        pub vendor: String,
+        pub version: String,
+        pub shading_language_version: String,
+    }
+
+    impl GpuInfo {
+        pub fn query(gl: &Gl) -> GpuInfo {
+            GpuInfo {
+                renderer: gl.renderer(),
+                vendor: gl.vendor(),
+                version: gl.get_string(ffi::VERSION),
+                shading_language_version: gl.get_string(ffi::SHADING_LANGUAGE_VERSION),
+            }
+        }
+    }
+
+    /// The flags a context was created with, as reported by CONTEXT_FLAGS.
+    #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    pub struct ContextFlags {
+        pub debug: bool,
+        pub forward_compatible: bool,
+        pub robust_access: bool,
+    }
+
+    impl ContextFlags {
+        pub fn from_gl(flags: GLenum) -> ContextFlags {
+            ContextFlags {
+                debug: flags & ffi::CONTEXT_FLAG_DEBUG_BIT != 0,
+                forward_compatible: flags & ffi::CONTEXT_FLAG_FORWARD_COMPATIBLE_BIT != 0,
+                robust_access: flags & ffi::CONTEXT_FLAG_ROBUST_ACCESS_BIT != 0,
+            }
+        }
+    }
+
+    /// What a context can do with textures of an internal format.
+    #[derive(Copy, Clone, Debug, Default, PartialEq)]
+    pub struct FormatCaps {
+        pub supported: bool,
+        pub color_renderable: bool,
+        pub filterable: bool,
+    }
+
+    /// A depth-only framebuffer and the depth texture attached to it, as
+    /// created by `Gl::create_shadow_map`.
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub struct ShadowMap {
+        pub framebuffer: GLuint,
+        pub texture: GLuint,
+    }
+
+    /// The color, depth and stencil write masks, for saving and restoring
+    /// around rendering that changes them.
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub struct WriteMask {
+        pub color: [bool; 4],
+        pub depth: bool,
+        pub stencil: u32,
+    }
+
+    impl WriteMask {
+        pub fn capture(gl: &Gl) -> WriteMask {
+            let mut color = [FALSE; 4];
+            let mut depth = [FALSE];
+            let mut stencil = [0];
+            unsafe {
+                gl.get_boolean_v(ffi::COLOR_WRITEMASK, &mut color);
+                gl.get_boolean_v(ffi::DEPTH_WRITEMASK, &mut depth);
+                gl.get_integer_v(ffi::STENCIL_WRITEMASK, &mut stencil);
+            }
+            WriteMask {
+                color: [
+                    color[0] != FALSE,
+                    color[1] != FALSE,
+                    color[2] != FALSE,
+                    color[3] != FALSE,
+                ],
+                depth: depth[0] != FALSE,
+                stencil: stencil[0] as u32,
+            }
+        }
+
+        pub fn apply(&self, gl: &Gl) {
+            let [r, g, b, a] = self.color;
+            gl.color_mask(r, g, b, a);
+            gl.depth_mask(self.depth);
+            gl.stencil_mask(self.stencil);
+        }
+    }
+
     impl Gl {
+        /// Recording and mock contexts report themselves as desktop GL.
         pub fn get_type(&self) -> GlType {
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(..) => GlType::Gl,
+                Gl::Recording(..) | Gl::Mock(..) => GlType::Gl,
                 Gl::Gles(..) => GlType::Gles,
+                Gl::Debug(inner, _) => inner.get_type(),
+            }
+        }
+
+        fn context_state(&self) -> Option<&ContextState> {
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => Some(&gl.state),
+                Gl::Gles(gles) => Some(&gles.state),
+                Gl::Debug(inner, _) => inner.context_state(),
+                _ => None,
             }
         }
 
+        #[cfg(not(feature = "gles-only"))]
         pub fn gl_fns(gl: self::ffi_gl::Gl) -> Rc<Gl> {
-            Rc::new(Gl::Gl(gl))
+            Rc::new(Gl::Gl(gl.into()))
         }
 
         pub fn gles_fns(gl: self::ffi_gles::Gles2) -> Rc<Gl> {
-            Rc::new(Gl::Gles(gl))
+            Rc::new(Gl::Gles(gl.into()))
+        }
+
+        /// Loads the entry points for `gl_type` using `loader`, which looks
+        /// up a function by name like `eglGetProcAddress` does.
+        ///
+        /// # Panics
+        ///
+        /// With the `gles-only` feature, if `gl_type` is `GlType::Gl`.
+        pub fn load_with(gl_type: GlType, mut loader: impl FnMut(&str) -> *const c_void) -> Rc<Gl> {
+            match gl_type {
+                #[cfg(not(feature = "gles-only"))]
+                GlType::Gl => Gl::gl_fns(ffi_gl::Gl::load_with(&mut loader)),
+                #[cfg(feature = "gles-only")]
+                GlType::Gl => {
+                    panic!("cannot load desktop GL: sparkle was built with the gles-only feature")
+                },
+                GlType::Gles => Gl::gles_fns(ffi_gles::Gles2::load_with(&mut loader)),
+            }
+        }
+
+        pub fn recording_fns() -> Rc<Gl> {
+            Rc::new(Gl::Recording(RefCell::new(vec![])))
+        }
+
+        /// Returns the calls recorded so far, clearing the log. Always empty
+        /// for contexts that aren't recording.
+        pub fn take_log(&self) -> Vec<GlCall> {
+            match self {
+                Gl::Recording(log) => log.replace(vec![]),
+                Gl::Debug(inner, _) => inner.take_log(),
+                _ => vec![],
+            }
+        }
+
+        pub fn mock_fns() -> Rc<Gl> {
+            Rc::new(Gl::Mock(MockState::default()))
+        }
+
+        /// Sets the value `get_string(which)` returns on a mock context.
+        pub fn set_mock_string(&self, which: GLenum, value: &str) {
+            match self {
+                Gl::Mock(state) => {
+                    state.strings.borrow_mut().insert(which, value.to_string());
+                },
+                _ => panic!("set_mock_string called on a context that isn't a mock"),
+            }
+        }
+
+        /// Queues `error` to be returned by a later `get_error` on a mock
+        /// context. Errors are returned in the order they were pushed.
+        pub fn push_mock_error(&self, error: GLenum) {
+            match self {
+                Gl::Mock(state) => state.errors.borrow_mut().push_back(error),
+                _ => panic!("push_mock_error called on a context that isn't a mock"),
+            }
+        }
+
+        /// Wraps `inner` so that any error raised by a call is reported along
+        /// with the name of the method that raised it.
+        pub fn debug_fns(inner: Rc<Gl>, panic_on_error: bool) -> Rc<Gl> {
+            Rc::new(Gl::Debug(inner, panic_on_error))
+        }
+
+        fn check_debug_error(&self, method: &str, panic_on_error: bool) {
+            let error = self.get_error();
+            if error != ffi::NO_ERROR {
+                let message = format!("{} raised {:?}", method, GlError::from_gl(error));
+                if panic_on_error {
+                    panic!("{}", message);
+                }
+                log::error!("{}", message);
+            }
+        }
+
+        fn record(&self, call: GlCall) {
+            if let Gl::Recording(log) = self {
+                log.borrow_mut().push(call);
+            }
+        }
+
+        fn mock_id(&self) -> GLuint {
+            match self {
+                Gl::Mock(state) => state.next_id(),
+                _ => 0,
+            }
+        }
+
+        fn mock_ids(&self, ids: &mut [GLuint]) {
+            for id in ids {
+                *id = self.mock_id();
+            }
         }
 
         pub fn gen_framebuffers(&self, n: GLsizei) -> Vec<GLuint> {
+            forward_debug!(self.gen_framebuffers(n));
             let mut ids = vec![0 as GLuint; n as usize];
+            self.gen_framebuffers_recorded_as("gen_framebuffers", &mut ids);
+            ids
+        }
+
+        /// Like `gen_framebuffers`, but fills `ids` rather than allocating.
+        pub fn gen_framebuffers_into(&self, ids: &mut [GLuint]) {
+            forward_debug!(self.gen_framebuffers_into(ids));
+            self.gen_framebuffers_recorded_as("gen_framebuffers_into", ids)
+        }
+
+        fn gen_framebuffers_recorded_as(&self, call: &'static str, ids: &mut [GLuint]) {
+            let n = ids.len() as GLsizei;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.GenFramebuffers(n, ids.as_mut_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.GenFramebuffers(n, ids.as_mut_ptr()) },
+                _ => {
+                    self.record(GlCall::Other(call));
+                    self.mock_ids(ids);
+                },
             }
-            ids
         }
 
         pub fn gen_textures(&self, n: GLsizei) -> Vec<GLuint> {
+            forward_debug!(self.gen_textures(n));
             let mut ids = vec![0 as GLuint; n as usize];
+            self.gen_textures_recorded_as("gen_textures", &mut ids);
+            ids
+        }
+
+        /// Like `gen_textures`, but fills `ids` rather than allocating.
+        pub fn gen_textures_into(&self, ids: &mut [GLuint]) {
+            forward_debug!(self.gen_textures_into(ids));
+            self.gen_textures_recorded_as("gen_textures_into", ids)
+        }
+
+        fn gen_textures_recorded_as(&self, call: &'static str, ids: &mut [GLuint]) {
+            let n = ids.len() as GLsizei;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.GenTextures(n, ids.as_mut_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.GenTextures(n, ids.as_mut_ptr()) },
+                _ => {
+                    self.record(GlCall::Other(call));
+                    self.mock_ids(ids);
+                },
             }
-            ids
         }
 
         pub fn gen_renderbuffers(&self, n: GLsizei) -> Vec<GLuint> {
+            forward_debug!(self.gen_renderbuffers(n));
             let mut ids = vec![0 as GLuint; n as usize];
+            self.gen_renderbuffers_recorded_as("gen_renderbuffers", &mut ids);
+            ids
+        }
+
+        /// Like `gen_renderbuffers`, but fills `ids` rather than allocating.
+        pub fn gen_renderbuffers_into(&self, ids: &mut [GLuint]) {
+            forward_debug!(self.gen_renderbuffers_into(ids));
+            self.gen_renderbuffers_recorded_as("gen_renderbuffers_into", ids)
+        }
+
+        fn gen_renderbuffers_recorded_as(&self, call: &'static str, ids: &mut [GLuint]) {
+            let n = ids.len() as GLsizei;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.GenRenderbuffers(n, ids.as_mut_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.GenRenderbuffers(n, ids.as_mut_ptr()) },
+                _ => {
+                    self.record(GlCall::Other(call));
+                    self.mock_ids(ids);
+                },
             }
-            ids
         }
 
         pub fn gen_buffers(&self, n: GLsizei) -> Vec<GLuint> {
+            forward_debug!(self.gen_buffers(n));
             let mut ids = vec![0 as GLuint; n as usize];
+            self.gen_buffers_recorded_as("gen_buffers", &mut ids);
+            ids
+        }
+
+        /// Like `gen_buffers`, but fills `ids` rather than allocating.
+        pub fn gen_buffers_into(&self, ids: &mut [GLuint]) {
+            forward_debug!(self.gen_buffers_into(ids));
+            self.gen_buffers_recorded_as("gen_buffers_into", ids)
+        }
+
+        fn gen_buffers_recorded_as(&self, call: &'static str, ids: &mut [GLuint]) {
+            let n = ids.len() as GLsizei;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.GenBuffers(n, ids.as_mut_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.GenBuffers(n, ids.as_mut_ptr()) },
+                _ => {
+                    self.record(GlCall::Other(call));
+                    self.mock_ids(ids);
+                },
             }
-            ids
         }
 
         pub fn gen_vertex_arrays(&self, n: GLsizei) -> Vec<GLuint> {
+            forward_debug!(self.gen_vertex_arrays(n));
             let mut ids = vec![0 as GLuint; n as usize];
+            self.gen_vertex_arrays_recorded_as("gen_vertex_arrays", &mut ids);
+            ids
+        }
+
+        /// Like `gen_vertex_arrays`, but fills `ids` rather than allocating.
+        pub fn gen_vertex_arrays_into(&self, ids: &mut [GLuint]) {
+            forward_debug!(self.gen_vertex_arrays_into(ids));
+            self.gen_vertex_arrays_recorded_as("gen_vertex_arrays_into", ids)
+        }
+
+        fn gen_vertex_arrays_recorded_as(&self, call: &'static str, ids: &mut [GLuint]) {
+            let n = ids.len() as GLsizei;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.GenVertexArrays(n, ids.as_mut_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.GenVertexArrays(n, ids.as_mut_ptr()) },
+                _ => {
+                    self.record(GlCall::Other(call));
+                    self.mock_ids(ids);
+                },
             }
-            ids
         }
 
         pub fn shader_source(&self, shader: GLuint, strings: &[&[u8]]) {
+            forward_debug!(self.shader_source(shader, strings));
             let pointers: Vec<*const u8> =
                 strings.iter().map(|string| (*string).as_ptr()).collect();
             let lengths: Vec<GLint> = strings.iter().map(|string| string.len() as GLint).collect();
             let len = pointers.len() as GLsizei;
             let pointers = pointers.as_ptr() as *const *const GLchar;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.ShaderSource(shader, len, pointers, lengths.as_ptr()) },
                 Gl::Gles(gles) => unsafe {
                     gles.ShaderSource(shader, len, pointers, lengths.as_ptr())
                 },
+                _ => self.record(GlCall::Other("shader_source")),
+            }
+        }
+
+        /// Sets a shader's source from a single string, without the
+        /// allocations `shader_source` makes for its pointer and length arrays.
+        pub fn shader_source_str(&self, shader: GLuint, source: &str) {
+            forward_debug!(self.shader_source_str(shader, source));
+            let pointer = source.as_ptr() as *const GLchar;
+            let length = source.len() as GLint;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.ShaderSource(shader, 1, &pointer, &length) },
+                Gl::Gles(gles) => unsafe { gles.ShaderSource(shader, 1, &pointer, &length) },
+                _ => self.record(GlCall::Other("shader_source_str")),
             }
         }
 
         pub fn create_program(&self) -> GLuint {
+            forward_debug!(self.create_program());
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.CreateProgram() },
                 Gl::Gles(gles) => unsafe { gles.CreateProgram() },
+                _ => {
+                    self.record(GlCall::Other("create_program"));
+                    self.mock_id()
+                },
             }
         }
 
@@ -122,6 +803,17 @@ pub mod gl {
             ty: GLenum,
             source: TexImageSource,
         ) {
+            forward_debug!(self.tex_image_2d(
+                target,
+                level,
+                internal_format,
+                width,
+                height,
+                border,
+                format,
+                ty,
+                source
+            ));
             let data = match source {
                 TexImageSource::Pixels(pixels) => {
                     pixels.map(|d| d.as_ptr()).unwrap_or(ptr::null()) as *const _
@@ -134,6 +826,7 @@ pub mod gl {
                 }
             };
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.TexImage2D(
                         target,
@@ -160,9 +853,45 @@ pub mod gl {
                         data,
                     )
                 },
+                _ => self.record(GlCall::Other("tex_image_2d")),
             }
         }
 
+        /// Uploads tightly-packed `pixels` with `tex_image_2d`, first flipping
+        /// the rows and/or premultiplying the color by alpha on the CPU, as
+        /// WebGL's UNPACK_FLIP_Y_WEBGL and UNPACK_PREMULTIPLY_ALPHA_WEBGL would.
+        /// Premultiplication is only supported for UNSIGNED_BYTE data, and has
+        /// no effect on formats without an alpha channel.
+        pub fn upload_texture_2d_with_options(
+            &self,
+            target: GLenum,
+            level: GLint,
+            internal_format: GLint,
+            width: GLsizei,
+            height: GLsizei,
+            format: GLenum,
+            ty: GLenum,
+            pixels: &[u8],
+            flip_y: bool,
+            premultiply: bool,
+        ) {
+            let data = apply_unpack_options(width, height, format, ty, pixels, flip_y, premultiply);
+            let previous_alignment = self.get_integer(ffi::UNPACK_ALIGNMENT);
+            self.pixel_store_i(ffi::UNPACK_ALIGNMENT, 1);
+            self.tex_image_2d(
+                target,
+                level,
+                internal_format,
+                width,
+                height,
+                0,
+                format,
+                ty,
+                TexImageSource::Pixels(Some(&data)),
+            );
+            self.pixel_store_i(ffi::UNPACK_ALIGNMENT, previous_alignment);
+        }
+
         pub fn tex_sub_image_2d(
             &self,
             target: GLenum,
@@ -175,8 +904,12 @@ pub mod gl {
             ty: GLenum,
             data: &[u8],
         ) {
+            forward_debug!(self.tex_sub_image_2d(
+                target, level, xoffset, yoffset, width, height, format, ty, data
+            ));
             let data = data.as_ptr() as *const c_void;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.TexSubImage2D(
                         target, level, xoffset, yoffset, width, height, format, ty, data,
@@ -187,6 +920,7 @@ pub mod gl {
                         target, level, xoffset, yoffset, width, height, format, ty, data,
                     )
                 },
+                _ => self.record(GlCall::Other("tex_sub_image_2d")),
             }
         }
 
@@ -201,13 +935,25 @@ pub mod gl {
             height: GLsizei,
             border: GLint,
         ) {
-            match self {
+            forward_debug!(self.copy_tex_image_2d(
+                target,
+                level,
+                internal_format,
+                x,
+                y,
+                width,
+                height,
+                border
+            ));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.CopyTexImage2D(target, level, internal_format, x, y, width, height, border)
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.CopyTexImage2D(target, level, internal_format, x, y, width, height, border)
                 },
+                _ => self.record(GlCall::Other("copy_tex_image_2d")),
             }
         }
 
@@ -222,13 +968,68 @@ pub mod gl {
             width: GLsizei,
             height: GLsizei,
         ) {
+            forward_debug!(
+                self.copy_tex_sub_image_2d(target, level, xoffset, yoffset, x, y, width, height)
+            );
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.CopyTexSubImage2D(target, level, xoffset, yoffset, x, y, width, height)
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.CopyTexSubImage2D(target, level, xoffset, yoffset, x, y, width, height)
                 },
+                _ => self.record(GlCall::Other("copy_tex_sub_image_2d")),
+            }
+        }
+
+        /// Copies a region between two textures or renderbuffers directly,
+        /// without going through a framebuffer blit. A no-op where unsupported.
+        pub fn copy_image_sub_data(
+            &self,
+            src_name: GLuint,
+            src_target: GLenum,
+            src_level: GLint,
+            src_x: GLint,
+            src_y: GLint,
+            src_z: GLint,
+            dst_name: GLuint,
+            dst_target: GLenum,
+            dst_level: GLint,
+            dst_x: GLint,
+            dst_y: GLint,
+            dst_z: GLint,
+            width: GLsizei,
+            height: GLsizei,
+            depth: GLsizei,
+        ) {
+            forward_debug!(self.copy_image_sub_data(
+                src_name, src_target, src_level, src_x, src_y, src_z, dst_name, dst_target,
+                dst_level, dst_x, dst_y, dst_z, width, height, depth
+            ));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.CopyImageSubData.is_loaded() {
+                        unsafe {
+                            gl.CopyImageSubData(
+                                src_name, src_target, src_level, src_x, src_y, src_z, dst_name,
+                                dst_target, dst_level, dst_x, dst_y, dst_z, width, height, depth,
+                            )
+                        }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.CopyImageSubData.is_loaded() {
+                        unsafe {
+                            gles.CopyImageSubData(
+                                src_name, src_target, src_level, src_x, src_y, src_z, dst_name,
+                                dst_target, dst_level, dst_x, dst_y, dst_z, width, height, depth,
+                            )
+                        }
+                    }
+                },
+                _ => self.record(GlCall::Other("copy_image_sub_data")),
             }
         }
 
@@ -242,9 +1043,19 @@ pub mod gl {
             border: GLint,
             data: &[u8],
         ) {
+            forward_debug!(self.compressed_tex_image_2d(
+                target,
+                level,
+                internal_format,
+                width,
+                height,
+                border,
+                data
+            ));
             let len = data.len() as GLsizei;
             let data = data.as_ptr() as *const c_void;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.CompressedTexImage2D(
                         target,
@@ -269,6 +1080,7 @@ pub mod gl {
                         data,
                     )
                 },
+                _ => self.record(GlCall::Other("compressed_tex_image_2d")),
             }
         }
 
@@ -283,9 +1095,13 @@ pub mod gl {
             format: GLenum,
             data: &[u8],
         ) {
+            forward_debug!(self.compressed_tex_sub_image_2d(
+                target, level, xoffset, yoffset, width, height, format, data
+            ));
             let len = data.len() as GLsizei;
             let data = data.as_ptr() as *const c_void;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.CompressedTexSubImage2D(
                         target, level, xoffset, yoffset, width, height, format, len, data,
@@ -296,6 +1112,7 @@ pub mod gl {
                         target, level, xoffset, yoffset, width, height, format, len, data,
                     )
                 },
+                _ => self.record(GlCall::Other("compressed_tex_sub_image_2d")),
             }
         }
 
@@ -307,13 +1124,16 @@ pub mod gl {
             width: GLsizei,
             height: GLsizei,
         ) {
+            forward_debug!(self.tex_storage_2d(target, levels, internal_format, width, height));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.TexStorage2D(target, levels, internal_format, width, height)
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.TexStorage2D(target, levels, internal_format, width, height)
                 },
+                _ => self.record(GlCall::Other("tex_storage_2d")),
             }
         }
 
@@ -326,71 +1146,292 @@ pub mod gl {
             height: GLsizei,
             depth: GLsizei,
         ) {
-            match self {
+            forward_debug!(self.tex_storage_3d(
+                target,
+                levels,
+                internal_format,
+                width,
+                height,
+                depth
+            ));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.TexStorage3D(target, levels, internal_format, width, height, depth)
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.TexStorage3D(target, levels, internal_format, width, height, depth)
                 },
+                _ => self.record(GlCall::Other("tex_storage_3d")),
             }
         }
 
-        pub fn generate_mipmap(&self, target: GLenum) {
-            match self {
-                Gl::Gl(gl) => unsafe { gl.GenerateMipmap(target) },
-                Gl::Gles(gles) => unsafe { gles.GenerateMipmap(target) },
-            }
-        }
-
-        pub fn active_texture(&self, texture: GLenum) {
-            match self {
-                Gl::Gl(gl) => unsafe { gl.ActiveTexture(texture) },
-                Gl::Gles(gles) => unsafe { gles.ActiveTexture(texture) },
-            }
-        }
-
-        pub fn attach_shader(&self, program: GLuint, shader: GLuint) {
-            match self {
-                Gl::Gl(gl) => unsafe { gl.AttachShader(program, shader) },
+        /// Creates a TEXTURE_2D with immutable storage for `levels` mip levels,
+        /// optionally uploading `pixels` to level 0. The new texture is left
+        /// bound to TEXTURE_2D.
+        pub fn create_texture_storage_2d(
+            &self,
+            internal_format: GLenum,
+            width: GLsizei,
+            height: GLsizei,
+            format: GLenum,
+            ty: GLenum,
+            pixels: Option<&[u8]>,
+            levels: GLsizei,
+        ) -> GLuint {
+            let texture = self.gen_textures(1)[0];
+            self.bind_texture(ffi::TEXTURE_2D, texture);
+            self.tex_storage_2d(ffi::TEXTURE_2D, levels, internal_format, width, height);
+            if let Some(pixels) = pixels {
+                self.tex_sub_image_2d(ffi::TEXTURE_2D, 0, 0, 0, width, height, format, ty, pixels);
+            }
+            texture
+        }
+
+        /// Creates a framebuffer with a `depth_format` texture as its only
+        /// attachment, set up for comparison sampling through a
+        /// `sampler2DShadow`. Both are left bound. If the framebuffer is
+        /// incomplete they are deleted, the previous bindings are restored and
+        /// the reason is returned instead.
+        pub fn create_shadow_map(
+            &self,
+            width: GLsizei,
+            height: GLsizei,
+            depth_format: GLenum,
+        ) -> Result<ShadowMap, String> {
+            let previous_texture = self.get_integer(ffi::TEXTURE_BINDING_2D) as GLuint;
+            let previous_draw_framebuffer =
+                self.get_integer(ffi::DRAW_FRAMEBUFFER_BINDING) as GLuint;
+            let previous_read_framebuffer =
+                self.get_integer(ffi::READ_FRAMEBUFFER_BINDING) as GLuint;
+            let texture = self.gen_textures(1)[0];
+            self.bind_texture(ffi::TEXTURE_2D, texture);
+            self.tex_storage_2d(ffi::TEXTURE_2D, 1, depth_format, width, height);
+            self.tex_parameter_i(
+                ffi::TEXTURE_2D,
+                ffi::TEXTURE_MIN_FILTER,
+                ffi::LINEAR as GLint,
+            );
+            self.tex_parameter_i(
+                ffi::TEXTURE_2D,
+                ffi::TEXTURE_MAG_FILTER,
+                ffi::LINEAR as GLint,
+            );
+            self.tex_parameter_i(
+                ffi::TEXTURE_2D,
+                ffi::TEXTURE_WRAP_S,
+                ffi::CLAMP_TO_EDGE as GLint,
+            );
+            self.tex_parameter_i(
+                ffi::TEXTURE_2D,
+                ffi::TEXTURE_WRAP_T,
+                ffi::CLAMP_TO_EDGE as GLint,
+            );
+            self.set_depth_compare(ffi::TEXTURE_2D, ffi::LEQUAL);
+
+            let framebuffer = self.gen_framebuffers(1)[0];
+            self.bind_framebuffer(ffi::FRAMEBUFFER, framebuffer);
+            self.framebuffer_texture_2d(
+                ffi::FRAMEBUFFER,
+                ffi::DEPTH_ATTACHMENT,
+                ffi::TEXTURE_2D,
+                texture,
+                0,
+            );
+            self.draw_buffers(&[ffi::NONE]);
+            self.read_buffer(ffi::NONE);
+
+            if let Err(message) = self.diagnose_framebuffer(ffi::FRAMEBUFFER) {
+                self.bind_framebuffer(ffi::DRAW_FRAMEBUFFER, previous_draw_framebuffer);
+                self.bind_framebuffer(ffi::READ_FRAMEBUFFER, previous_read_framebuffer);
+                self.bind_texture(ffi::TEXTURE_2D, previous_texture);
+                self.delete_framebuffers(&[framebuffer]);
+                self.delete_textures(&[texture]);
+                return Err(message);
+            }
+            Ok(ShadowMap {
+                framebuffer,
+                texture,
+            })
+        }
+
+        pub fn generate_mipmap(&self, target: GLenum) {
+            forward_debug!(self.generate_mipmap(target));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.GenerateMipmap(target) },
+                Gl::Gles(gles) => unsafe { gles.GenerateMipmap(target) },
+                _ => self.record(GlCall::Other("generate_mipmap")),
+            }
+        }
+
+        /// Uses `image` as the storage for the texture bound to `target`, which
+        /// is usually TEXTURE_EXTERNAL_OES. GLES only; a no-op on desktop GL.
+        ///
+        /// # Safety
+        ///
+        /// `image` must be a valid EGLImage for the current display.
+        pub unsafe fn egl_image_target_texture_2d_oes(&self, target: GLenum, image: GLeglImageOES) {
+            forward_debug!(self.egl_image_target_texture_2d_oes(target, image));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(..) => {},
+                Gl::Gles(gles) => {
+                    if gles.EGLImageTargetTexture2DOES.is_loaded() {
+                        gles.EGLImageTargetTexture2DOES(target, image)
+                    }
+                },
+                _ => self.record(GlCall::Other("egl_image_target_texture_2d_oes")),
+            }
+        }
+
+        /// Uses `image` as the storage for the renderbuffer bound to `target`.
+        /// GLES only; a no-op on desktop GL.
+        ///
+        /// # Safety
+        ///
+        /// `image` must be a valid EGLImage for the current display.
+        pub unsafe fn egl_image_target_renderbuffer_storage_oes(
+            &self,
+            target: GLenum,
+            image: GLeglImageOES,
+        ) {
+            forward_debug!(self.egl_image_target_renderbuffer_storage_oes(target, image));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(..) => {},
+                Gl::Gles(gles) => {
+                    if gles.EGLImageTargetRenderbufferStorageOES.is_loaded() {
+                        gles.EGLImageTargetRenderbufferStorageOES(target, image)
+                    }
+                },
+                _ => self.record(GlCall::Other("egl_image_target_renderbuffer_storage_oes")),
+            }
+        }
+
+        pub fn active_texture(&self, texture: GLenum) {
+            forward_debug!(self.active_texture(texture));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.ActiveTexture(texture) },
+                Gl::Gles(gles) => unsafe { gles.ActiveTexture(texture) },
+                _ => self.record(GlCall::ActiveTexture(texture)),
+            }
+        }
+
+        pub fn attach_shader(&self, program: GLuint, shader: GLuint) {
+            forward_debug!(self.attach_shader(program, shader));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.AttachShader(program, shader) },
                 Gl::Gles(gles) => unsafe { gles.AttachShader(program, shader) },
+                _ => self.record(GlCall::Other("attach_shader")),
             }
         }
 
         pub fn create_shader(&self, shader_type: GLenum) -> GLuint {
+            forward_debug!(self.create_shader(shader_type));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.CreateShader(shader_type) },
                 Gl::Gles(gles) => unsafe { gles.CreateShader(shader_type) },
+                _ => {
+                    self.record(GlCall::Other("create_shader"));
+                    self.mock_id()
+                },
+            }
+        }
+
+        /// Creates and compiles a shader from `sources`. On failure the shader
+        /// is deleted and its info log is returned.
+        pub fn compile_shader_checked(
+            &self,
+            shader_type: GLenum,
+            sources: &[&[u8]],
+        ) -> Result<GLuint, String> {
+            let shader = self.create_shader(shader_type);
+            self.shader_source(shader, sources);
+            self.compile_shader(shader);
+            let mut status = [0];
+            unsafe {
+                self.get_shader_iv(shader, ffi::COMPILE_STATUS, &mut status);
+            }
+            if status[0] == 0 {
+                let log = self.get_shader_info_log(shader);
+                self.delete_shader(shader);
+                return Err(log);
             }
+            Ok(shader)
         }
 
         pub fn delete_shader(&self, shader: GLuint) {
+            forward_debug!(self.delete_shader(shader));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.DeleteShader(shader) },
                 Gl::Gles(gles) => unsafe { gles.DeleteShader(shader) },
+                _ => self.record(GlCall::Other("delete_shader")),
             }
         }
 
         pub fn detach_shader(&self, program: GLuint, shader: GLuint) {
+            forward_debug!(self.detach_shader(program, shader));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.DetachShader(program, shader) },
                 Gl::Gles(gles) => unsafe { gles.DetachShader(program, shader) },
+                _ => self.record(GlCall::Other("detach_shader")),
+            }
+        }
+
+        pub fn get_attached_shaders(&self, program: GLuint) -> Vec<GLuint> {
+            forward_debug!(self.get_attached_shaders(program));
+            let mut max_count = [0];
+            unsafe {
+                self.get_program_iv(program, ffi::ATTACHED_SHADERS, &mut max_count);
             }
+            if max_count[0] <= 0 {
+                return Vec::new();
+            }
+            let mut shaders = vec![0 as GLuint; max_count[0] as usize];
+            let mut count = 0 as GLsizei;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.GetAttachedShaders(program, max_count[0], &mut count, shaders.as_mut_ptr())
+                },
+                Gl::Gles(gles) => unsafe {
+                    gles.GetAttachedShaders(program, max_count[0], &mut count, shaders.as_mut_ptr())
+                },
+                _ => self.record(GlCall::Other("get_attached_shaders")),
+            }
+            shaders.truncate(if count > 0 { count as usize } else { 0 });
+            shaders
         }
 
         pub fn bind_buffer(&self, target: GLenum, buffer: GLuint) {
+            forward_debug!(self.bind_buffer(target, buffer));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.BindBuffer(target, buffer) },
                 Gl::Gles(gles) => unsafe { gles.BindBuffer(target, buffer) },
+                _ => self.record(GlCall::BindBuffer(target, buffer)),
             }
         }
 
+        pub fn bind_buffer_typed(&self, target: BufferTarget, buffer: GLuint) {
+            self.bind_buffer(target.to_gl(), buffer)
+        }
+
         pub fn delete_buffers(&self, buffers: &[GLuint]) {
+            forward_debug!(self.delete_buffers(buffers));
             let len = buffers.len() as GLsizei;
             let buffers = buffers.as_ptr();
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.DeleteBuffers(len, buffers) },
                 Gl::Gles(gles) => unsafe { gles.DeleteBuffers(len, buffers) },
+                _ => self.record(GlCall::Other("delete_buffers")),
             }
         }
 
@@ -402,9 +1443,28 @@ pub mod gl {
             write_offset: isize,
             size: isize,
         ) {
-            match self {
-                Gl::Gl(gl) => unsafe { gl.CopyBufferSubData(read_target, write_target, read_offset, write_offset, size) },
-                Gl::Gles(gles) => unsafe { gles.CopyBufferSubData(read_target, write_target, read_offset, write_offset, size) },
+            forward_debug!(self.copy_buffer_sub_data(
+                read_target,
+                write_target,
+                read_offset,
+                write_offset,
+                size
+            ));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.CopyBufferSubData(read_target, write_target, read_offset, write_offset, size)
+                },
+                Gl::Gles(gles) => unsafe {
+                    gles.CopyBufferSubData(
+                        read_target,
+                        write_target,
+                        read_offset,
+                        write_offset,
+                        size,
+                    )
+                },
+                _ => self.record(GlCall::Other("copy_buffer_sub_data")),
             }
         }
 
@@ -415,66 +1475,153 @@ pub mod gl {
             length: GLsizeiptr,
             access: GLbitfield,
         ) -> *mut c_void {
+            forward_debug!(self.map_buffer_range(target, offset, length, access));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.MapBufferRange(target, offset, length, access) },
                 Gl::Gles(gles) => unsafe { gles.MapBufferRange(target, offset, length, access) },
+                _ => {
+                    self.record(GlCall::Other("map_buffer_range"));
+                    ptr::null_mut()
+                },
+            }
+        }
+
+        /// Maps a range of the buffer bound to `target`, returning a guard that
+        /// unmaps it when dropped. Returns `None` if the mapping failed.
+        pub fn map_buffer_range_mut(
+            &self,
+            target: GLenum,
+            offset: GLintptr,
+            length: GLsizeiptr,
+            access: GLbitfield,
+        ) -> Option<MappedBuffer<'_>> {
+            assert!(offset >= 0);
+            assert!(length >= 0);
+            let ptr = self.map_buffer_range(target, offset, length, access);
+            if ptr.is_null() {
+                return None;
+            }
+            Some(MappedBuffer {
+                gl: self,
+                target,
+                ptr: ptr as *mut u8,
+                len: length as usize,
+            })
+        }
+
+        pub fn flush_mapped_buffer_range(
+            &self,
+            target: GLenum,
+            offset: GLintptr,
+            length: GLsizeiptr,
+        ) {
+            forward_debug!(self.flush_mapped_buffer_range(target, offset, length));
+            assert!(offset >= 0);
+            assert!(length >= 0);
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.FlushMappedBufferRange(target, offset, length) },
+                Gl::Gles(gles) => unsafe { gles.FlushMappedBufferRange(target, offset, length) },
+                _ => self.record(GlCall::Other("flush_mapped_buffer_range")),
             }
         }
 
         pub fn unmap_buffer(&self, target: GLenum) {
+            forward_debug!(self.unmap_buffer(target));
             match self {
-                Gl::Gl(gl) => unsafe { gl.UnmapBuffer(target); },
-                Gl::Gles(gles) => unsafe { gles.UnmapBuffer(target); },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.UnmapBuffer(target);
+                },
+                Gl::Gles(gles) => unsafe {
+                    gles.UnmapBuffer(target);
+                },
+                _ => self.record(GlCall::Other("unmap_buffer")),
             }
         }
 
         pub fn link_program(&self, program: GLuint) {
+            forward_debug!(self.link_program(program));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.LinkProgram(program) },
                 Gl::Gles(gles) => unsafe { gles.LinkProgram(program) },
+                _ => self.record(GlCall::Other("link_program")),
             }
         }
 
+        /// Links `program`, returning its info log if linking failed.
+        pub fn link_program_checked(&self, program: GLuint) -> Result<(), String> {
+            self.link_program(program);
+            let mut status = [0];
+            unsafe {
+                self.get_program_iv(program, ffi::LINK_STATUS, &mut status);
+            }
+            if status[0] == 0 {
+                return Err(self.get_program_info_log(program));
+            }
+            Ok(())
+        }
+
         pub fn validate_program(&self, program: GLuint) {
+            forward_debug!(self.validate_program(program));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.ValidateProgram(program) },
                 Gl::Gles(gles) => unsafe { gles.ValidateProgram(program) },
+                _ => self.record(GlCall::Other("validate_program")),
             }
         }
 
         pub fn delete_program(&self, program: GLuint) {
+            forward_debug!(self.delete_program(program));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.DeleteProgram(program) },
                 Gl::Gles(gles) => unsafe { gles.DeleteProgram(program) },
+                _ => self.record(GlCall::Other("delete_program")),
             }
         }
 
         pub fn delete_vertex_arrays(&self, vertex_arrays: &[GLuint]) {
+            forward_debug!(self.delete_vertex_arrays(vertex_arrays));
             let len = vertex_arrays.len() as GLsizei;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.DeleteVertexArrays(len, vertex_arrays.as_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.DeleteVertexArrays(len, vertex_arrays.as_ptr()) },
+                _ => self.record(GlCall::Other("delete_vertex_arrays")),
             }
         }
 
         pub fn bind_vertex_array(&self, vao: GLuint) {
+            forward_debug!(self.bind_vertex_array(vao));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.BindVertexArray(vao) },
                 Gl::Gles(gles) => unsafe { gles.BindVertexArray(vao) },
+                _ => self.record(GlCall::BindVertexArray(vao)),
             }
         }
 
         pub fn enable_vertex_attrib_array(&self, index: GLuint) {
+            forward_debug!(self.enable_vertex_attrib_array(index));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.EnableVertexAttribArray(index) },
                 Gl::Gles(gles) => unsafe { gles.EnableVertexAttribArray(index) },
+                _ => self.record(GlCall::Other("enable_vertex_attrib_array")),
             }
         }
 
         pub fn disable_vertex_attrib_array(&self, index: GLuint) {
+            forward_debug!(self.disable_vertex_attrib_array(index));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.DisableVertexAttribArray(index) },
                 Gl::Gles(gles) => unsafe { gles.DisableVertexAttribArray(index) },
+                _ => self.record(GlCall::Other("disable_vertex_attrib_array")),
             }
         }
 
@@ -486,9 +1633,12 @@ pub mod gl {
             z: GLfloat,
             w: GLfloat,
         ) {
+            forward_debug!(self.vertex_attrib_4f(index, x, y, z, w));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.VertexAttrib4f(index, x, y, z, w) },
                 Gl::Gles(gles) => unsafe { gles.VertexAttrib4f(index, x, y, z, w) },
+                _ => self.record(GlCall::Other("vertex_attrib_4f")),
             }
         }
 
@@ -500,9 +1650,12 @@ pub mod gl {
             z: GLint,
             w: GLint,
         ) {
+            forward_debug!(self.vertex_attrib_4i(index, x, y, z, w));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.VertexAttribI4i(index, x, y, z, w) },
                 Gl::Gles(gles) => unsafe { gles.VertexAttribI4i(index, x, y, z, w) },
+                _ => self.record(GlCall::Other("vertex_attrib_4i")),
             }
         }
 
@@ -514,9 +1667,72 @@ pub mod gl {
             z: GLuint,
             w: GLuint,
         ) {
+            forward_debug!(self.vertex_attrib_4ui(index, x, y, z, w));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.VertexAttribI4ui(index, x, y, z, w) },
                 Gl::Gles(gles) => unsafe { gles.VertexAttribI4ui(index, x, y, z, w) },
+                _ => self.record(GlCall::Other("vertex_attrib_4ui")),
+            }
+        }
+
+        pub fn vertex_attrib_1f(&self, index: GLuint, x: GLfloat) {
+            forward_debug!(self.vertex_attrib_1f(index, x));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.VertexAttrib1f(index, x) },
+                Gl::Gles(gles) => unsafe { gles.VertexAttrib1f(index, x) },
+                _ => self.record(GlCall::Other("vertex_attrib_1f")),
+            }
+        }
+
+        pub fn vertex_attrib_2f(&self, index: GLuint, x: GLfloat, y: GLfloat) {
+            forward_debug!(self.vertex_attrib_2f(index, x, y));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.VertexAttrib2f(index, x, y) },
+                Gl::Gles(gles) => unsafe { gles.VertexAttrib2f(index, x, y) },
+                _ => self.record(GlCall::Other("vertex_attrib_2f")),
+            }
+        }
+
+        pub fn vertex_attrib_3f(&self, index: GLuint, x: GLfloat, y: GLfloat, z: GLfloat) {
+            forward_debug!(self.vertex_attrib_3f(index, x, y, z));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.VertexAttrib3f(index, x, y, z) },
+                Gl::Gles(gles) => unsafe { gles.VertexAttrib3f(index, x, y, z) },
+                _ => self.record(GlCall::Other("vertex_attrib_3f")),
+            }
+        }
+
+        pub fn vertex_attrib_4fv(&self, index: GLuint, values: &[GLfloat; 4]) {
+            forward_debug!(self.vertex_attrib_4fv(index, values));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.VertexAttrib4fv(index, values.as_ptr()) },
+                Gl::Gles(gles) => unsafe { gles.VertexAttrib4fv(index, values.as_ptr()) },
+                _ => self.record(GlCall::Other("vertex_attrib_4fv")),
+            }
+        }
+
+        pub fn vertex_attrib_i4iv(&self, index: GLuint, values: &[GLint; 4]) {
+            forward_debug!(self.vertex_attrib_i4iv(index, values));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.VertexAttribI4iv(index, values.as_ptr()) },
+                Gl::Gles(gles) => unsafe { gles.VertexAttribI4iv(index, values.as_ptr()) },
+                _ => self.record(GlCall::Other("vertex_attrib_i4iv")),
+            }
+        }
+
+        pub fn vertex_attrib_i4uiv(&self, index: GLuint, values: &[GLuint; 4]) {
+            forward_debug!(self.vertex_attrib_i4uiv(index, values));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.VertexAttribI4uiv(index, values.as_ptr()) },
+                Gl::Gles(gles) => unsafe { gles.VertexAttribI4uiv(index, values.as_ptr()) },
+                _ => self.record(GlCall::Other("vertex_attrib_i4uiv")),
             }
         }
 
@@ -540,32 +1756,185 @@ pub mod gl {
             stride: GLsizei,
             offset: GLuint,
         ) {
+            forward_debug!(
+                self.vertex_attrib_pointer(index, size, type_, normalized, stride, offset)
+            );
             let normalized = normalized as GLboolean;
             let offset = offset as *const GLvoid;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.VertexAttribPointer(index, size, type_, normalized, stride, offset)
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.VertexAttribPointer(index, size, type_, normalized, stride, offset)
                 },
+                _ => self.record(GlCall::Other("vertex_attrib_pointer")),
+            }
+        }
+
+        pub fn vertex_attrib_i_pointer(
+            &self,
+            index: GLuint,
+            size: GLint,
+            type_: GLenum,
+            stride: GLsizei,
+            offset: GLuint,
+        ) {
+            forward_debug!(self.vertex_attrib_i_pointer(index, size, type_, stride, offset));
+            let offset = offset as *const GLvoid;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.VertexAttribIPointer(index, size, type_, stride, offset)
+                },
+                Gl::Gles(gles) => unsafe {
+                    gles.VertexAttribIPointer(index, size, type_, stride, offset)
+                },
+                _ => self.record(GlCall::Other("vertex_attrib_i_pointer")),
             }
         }
 
         pub fn vertex_attrib_divisor(&self, index: GLuint, divisor: GLuint) {
+            forward_debug!(self.vertex_attrib_divisor(index, divisor));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.VertexAttribDivisor(index, divisor) },
                 Gl::Gles(gles) => unsafe { gles.VertexAttribDivisor(index, divisor) },
+                _ => self.record(GlCall::Other("vertex_attrib_divisor")),
+            }
+        }
+
+        pub fn bind_vertex_buffer(
+            &self,
+            binding_index: GLuint,
+            buffer: GLuint,
+            offset: GLintptr,
+            stride: GLsizei,
+        ) {
+            forward_debug!(self.bind_vertex_buffer(binding_index, buffer, offset, stride));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.BindVertexBuffer.is_loaded() {
+                        unsafe { gl.BindVertexBuffer(binding_index, buffer, offset, stride) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.BindVertexBuffer.is_loaded() {
+                        unsafe { gles.BindVertexBuffer(binding_index, buffer, offset, stride) }
+                    }
+                },
+                _ => self.record(GlCall::Other("bind_vertex_buffer")),
+            }
+        }
+
+        pub fn vertex_attrib_format(
+            &self,
+            index: GLuint,
+            size: GLint,
+            type_: GLenum,
+            normalized: bool,
+            relative_offset: GLuint,
+        ) {
+            forward_debug!(self.vertex_attrib_format(
+                index,
+                size,
+                type_,
+                normalized,
+                relative_offset
+            ));
+            let normalized = normalized as GLboolean;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.VertexAttribFormat.is_loaded() {
+                        unsafe {
+                            gl.VertexAttribFormat(index, size, type_, normalized, relative_offset)
+                        }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.VertexAttribFormat.is_loaded() {
+                        unsafe {
+                            gles.VertexAttribFormat(index, size, type_, normalized, relative_offset)
+                        }
+                    }
+                },
+                _ => self.record(GlCall::Other("vertex_attrib_format")),
+            }
+        }
+
+        pub fn vertex_attrib_i_format(
+            &self,
+            index: GLuint,
+            size: GLint,
+            type_: GLenum,
+            relative_offset: GLuint,
+        ) {
+            forward_debug!(self.vertex_attrib_i_format(index, size, type_, relative_offset));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.VertexAttribIFormat.is_loaded() {
+                        unsafe { gl.VertexAttribIFormat(index, size, type_, relative_offset) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.VertexAttribIFormat.is_loaded() {
+                        unsafe { gles.VertexAttribIFormat(index, size, type_, relative_offset) }
+                    }
+                },
+                _ => self.record(GlCall::Other("vertex_attrib_i_format")),
+            }
+        }
+
+        pub fn vertex_attrib_binding(&self, index: GLuint, binding_index: GLuint) {
+            forward_debug!(self.vertex_attrib_binding(index, binding_index));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.VertexAttribBinding.is_loaded() {
+                        unsafe { gl.VertexAttribBinding(index, binding_index) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.VertexAttribBinding.is_loaded() {
+                        unsafe { gles.VertexAttribBinding(index, binding_index) }
+                    }
+                },
+                _ => self.record(GlCall::Other("vertex_attrib_binding")),
+            }
+        }
+
+        pub fn vertex_binding_divisor(&self, binding_index: GLuint, divisor: GLuint) {
+            forward_debug!(self.vertex_binding_divisor(binding_index, divisor));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.VertexBindingDivisor.is_loaded() {
+                        unsafe { gl.VertexBindingDivisor(binding_index, divisor) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.VertexBindingDivisor.is_loaded() {
+                        unsafe { gles.VertexBindingDivisor(binding_index, divisor) }
+                    }
+                },
+                _ => self.record(GlCall::Other("vertex_binding_divisor")),
             }
         }
 
         pub fn bind_attrib_location(&self, program: GLuint, index: GLuint, name: &str) {
+            forward_debug!(self.bind_attrib_location(program, index, name));
             let c_string = CString::new(name).unwrap();
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.BindAttribLocation(program, index, c_string.as_ptr()) },
                 Gl::Gles(gles) => unsafe {
                     gles.BindAttribLocation(program, index, c_string.as_ptr())
                 },
+                _ => self.record(GlCall::Other("bind_attrib_location")),
             }
         }
 
@@ -575,9 +1944,12 @@ pub mod gl {
             location: GLint,
             result: &mut [GLint],
         ) {
+            forward_debug!(self.get_uniform_iv(program, location, result));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => gl.GetUniformiv(program, location, result.as_mut_ptr()),
                 Gl::Gles(gles) => gles.GetUniformiv(program, location, result.as_mut_ptr()),
+                _ => self.record(GlCall::Other("get_uniform_iv")),
             }
         }
 
@@ -587,9 +1959,12 @@ pub mod gl {
             location: GLint,
             result: &mut [GLuint],
         ) {
+            forward_debug!(self.get_uniform_uiv(program, location, result));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => gl.GetUniformuiv(program, location, result.as_mut_ptr()),
                 Gl::Gles(gles) => gles.GetUniformuiv(program, location, result.as_mut_ptr()),
+                _ => self.record(GlCall::Other("get_uniform_uiv")),
             }
         }
 
@@ -599,30 +1974,42 @@ pub mod gl {
             location: GLint,
             result: &mut [GLfloat],
         ) {
+            forward_debug!(self.get_uniform_fv(program, location, result));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => gl.GetUniformfv(program, location, result.as_mut_ptr()),
                 Gl::Gles(gles) => gles.GetUniformfv(program, location, result.as_mut_ptr()),
+                _ => self.record(GlCall::Other("get_uniform_fv")),
             }
         }
 
         pub fn hint(&self, param_name: GLenum, param_val: GLenum) {
+            forward_debug!(self.hint(param_name, param_val));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Hint(param_name, param_val) },
                 Gl::Gles(gles) => unsafe { gles.Hint(param_name, param_val) },
+                _ => self.record(GlCall::Other("hint")),
             }
         }
 
         pub fn blend_color(&self, r: f32, g: f32, b: f32, a: f32) {
+            forward_debug!(self.blend_color(r, g, b, a));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.BlendColor(r, g, b, a) },
                 Gl::Gles(gles) => unsafe { gles.BlendColor(r, g, b, a) },
+                _ => self.record(GlCall::Other("blend_color")),
             }
         }
 
         pub fn blend_func(&self, sfactor: GLenum, dfactor: GLenum) {
+            forward_debug!(self.blend_func(sfactor, dfactor));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.BlendFunc(sfactor, dfactor) },
                 Gl::Gles(gles) => unsafe { gles.BlendFunc(sfactor, dfactor) },
+                _ => self.record(GlCall::BlendFunc(sfactor, dfactor)),
             }
         }
 
@@ -633,138 +2020,536 @@ pub mod gl {
             src_alpha: GLenum,
             dest_alpha: GLenum,
         ) {
+            forward_debug!(self.blend_func_separate(src_rgb, dest_rgb, src_alpha, dest_alpha));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.BlendFuncSeparate(src_rgb, dest_rgb, src_alpha, dest_alpha)
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.BlendFuncSeparate(src_rgb, dest_rgb, src_alpha, dest_alpha)
                 },
+                _ => self.record(GlCall::Other("blend_func_separate")),
+            }
+        }
+
+        /// Sets the color logic op used while COLOR_LOGIC_OP is enabled, which
+        /// replaces blending. GLES has no color logic op, so this is a no-op
+        /// there.
+        pub fn logic_op(&self, opcode: GLenum) {
+            forward_debug!(self.logic_op(opcode));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.LogicOp(opcode) },
+                Gl::Gles(..) => {},
+                _ => self.record(GlCall::Other("logic_op")),
             }
         }
 
         pub fn blend_equation(&self, mode: GLenum) {
+            forward_debug!(self.blend_equation(mode));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.BlendEquation(mode) },
                 Gl::Gles(gles) => unsafe { gles.BlendEquation(mode) },
+                _ => self.record(GlCall::BlendEquation(mode)),
             }
         }
 
         pub fn blend_equation_separate(&self, mode_rgb: GLenum, mode_alpha: GLenum) {
+            forward_debug!(self.blend_equation_separate(mode_rgb, mode_alpha));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.BlendEquationSeparate(mode_rgb, mode_alpha) },
                 Gl::Gles(gles) => unsafe { gles.BlendEquationSeparate(mode_rgb, mode_alpha) },
+                _ => self.record(GlCall::Other("blend_equation_separate")),
             }
         }
 
-        pub fn color_mask(&self, r: bool, g: bool, b: bool, a: bool) {
-            let (r, g, b, a) = (
-                r as GLboolean,
-                g as GLboolean,
-                b as GLboolean,
-                a as GLboolean,
-            );
+        pub fn blend_func_i(&self, buf: GLuint, sfactor: GLenum, dfactor: GLenum) {
+            forward_debug!(self.blend_func_i(buf, sfactor, dfactor));
             match self {
-                Gl::Gl(gl) => unsafe { gl.ColorMask(r, g, b, a) },
-                Gl::Gles(gles) => unsafe { gles.ColorMask(r, g, b, a) },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.BlendFunciARB.is_loaded() {
+                        unsafe { gl.BlendFunciARB(buf, sfactor, dfactor) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.BlendFunci.is_loaded() {
+                        unsafe { gles.BlendFunci(buf, sfactor, dfactor) }
+                    }
+                },
+                _ => self.record(GlCall::Other("blend_func_i")),
             }
         }
 
-        pub fn cull_face(&self, mode: GLenum) {
+        pub fn blend_func_separate_i(
+            &self,
+            buf: GLuint,
+            src_rgb: GLenum,
+            dest_rgb: GLenum,
+            src_alpha: GLenum,
+            dest_alpha: GLenum,
+        ) {
+            forward_debug!(
+                self.blend_func_separate_i(buf, src_rgb, dest_rgb, src_alpha, dest_alpha)
+            );
             match self {
-                Gl::Gl(gl) => unsafe { gl.CullFace(mode) },
-                Gl::Gles(gles) => unsafe { gles.CullFace(mode) },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.BlendFuncSeparateiARB.is_loaded() {
+                        unsafe {
+                            gl.BlendFuncSeparateiARB(buf, src_rgb, dest_rgb, src_alpha, dest_alpha)
+                        }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.BlendFuncSeparatei.is_loaded() {
+                        unsafe {
+                            gles.BlendFuncSeparatei(buf, src_rgb, dest_rgb, src_alpha, dest_alpha)
+                        }
+                    }
+                },
+                _ => self.record(GlCall::Other("blend_func_separate_i")),
             }
         }
 
-        pub fn front_face(&self, mode: GLenum) {
+        pub fn blend_equation_i(&self, buf: GLuint, mode: GLenum) {
+            forward_debug!(self.blend_equation_i(buf, mode));
             match self {
-                Gl::Gl(gl) => unsafe { gl.FrontFace(mode) },
-                Gl::Gles(gles) => unsafe { gles.FrontFace(mode) },
-            }
-        }
-
-        pub fn depth_func(&self, func: GLenum) {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.BlendEquationiARB.is_loaded() {
+                        unsafe { gl.BlendEquationiARB(buf, mode) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.BlendEquationi.is_loaded() {
+                        unsafe { gles.BlendEquationi(buf, mode) }
+                    }
+                },
+                _ => self.record(GlCall::Other("blend_equation_i")),
+            }
+        }
+
+        pub fn blend_equation_separate_i(&self, buf: GLuint, mode_rgb: GLenum, mode_alpha: GLenum) {
+            forward_debug!(self.blend_equation_separate_i(buf, mode_rgb, mode_alpha));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.BlendEquationSeparateiARB.is_loaded() {
+                        unsafe { gl.BlendEquationSeparateiARB(buf, mode_rgb, mode_alpha) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.BlendEquationSeparatei.is_loaded() {
+                        unsafe { gles.BlendEquationSeparatei(buf, mode_rgb, mode_alpha) }
+                    }
+                },
+                _ => self.record(GlCall::Other("blend_equation_separate_i")),
+            }
+        }
+
+        /// Enables blending for straight (non-premultiplied) alpha, drawing
+        /// the source over the destination.
+        pub fn set_alpha_blend(&self) {
+            self.enable(ffi::BLEND);
+            self.blend_func_separate(
+                ffi::SRC_ALPHA,
+                ffi::ONE_MINUS_SRC_ALPHA,
+                ffi::ONE,
+                ffi::ONE_MINUS_SRC_ALPHA,
+            );
+            self.blend_equation(ffi::FUNC_ADD);
+        }
+
+        /// Enables blending for premultiplied alpha, drawing the source over
+        /// the destination.
+        pub fn set_premultiplied_blend(&self) {
+            self.enable(ffi::BLEND);
+            self.blend_func_separate(
+                ffi::ONE,
+                ffi::ONE_MINUS_SRC_ALPHA,
+                ffi::ONE,
+                ffi::ONE_MINUS_SRC_ALPHA,
+            );
+            self.blend_equation(ffi::FUNC_ADD);
+        }
+
+        /// Enables blending that adds the source to the destination.
+        pub fn set_additive_blend(&self) {
+            self.enable(ffi::BLEND);
+            self.blend_func(ffi::ONE, ffi::ONE);
+            self.blend_equation(ffi::FUNC_ADD);
+        }
+
+        pub fn color_mask(&self, r: bool, g: bool, b: bool, a: bool) {
+            forward_debug!(self.color_mask(r, g, b, a));
+            let (r, g, b, a) = (
+                r as GLboolean,
+                g as GLboolean,
+                b as GLboolean,
+                a as GLboolean,
+            );
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.ColorMask(r, g, b, a) },
+                Gl::Gles(gles) => unsafe { gles.ColorMask(r, g, b, a) },
+                _ => self.record(GlCall::ColorMask(
+                    r != FALSE,
+                    g != FALSE,
+                    b != FALSE,
+                    a != FALSE,
+                )),
+            }
+        }
+
+        pub fn color_mask_i(&self, buf: GLuint, r: bool, g: bool, b: bool, a: bool) {
+            forward_debug!(self.color_mask_i(buf, r, g, b, a));
+            let (r, g, b, a) = (
+                r as GLboolean,
+                g as GLboolean,
+                b as GLboolean,
+                a as GLboolean,
+            );
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.ColorMaski(buf, r, g, b, a) },
+                Gl::Gles(gles) => {
+                    if gles.ColorMaski.is_loaded() {
+                        unsafe { gles.ColorMaski(buf, r, g, b, a) }
+                    }
+                },
+                _ => self.record(GlCall::Other("color_mask_i")),
+            }
+        }
+
+        /// GLES always uses the last vertex convention, so this is a no-op
+        /// there.
+        pub fn provoking_vertex(&self, mode: ProvokingVertex) {
+            forward_debug!(self.provoking_vertex(mode));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.ProvokingVertex(mode.to_gl()) },
+                Gl::Gles(..) => {},
+                _ => self.record(GlCall::Other("provoking_vertex")),
+            }
+        }
+
+        /// Sets the index that restarts primitives while PRIMITIVE_RESTART is
+        /// enabled. GLES only supports PRIMITIVE_RESTART_FIXED_INDEX, which
+        /// always uses the largest value of the index type, so this is a no-op
+        /// there.
+        pub fn primitive_restart_index(&self, index: GLuint) {
+            forward_debug!(self.primitive_restart_index(index));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.PrimitiveRestartIndex(index) },
+                Gl::Gles(..) => {},
+                _ => self.record(GlCall::Other("primitive_restart_index")),
+            }
+        }
+
+        pub fn get_provoking_vertex(&self) -> GLenum {
+            match self.get_type() {
+                GlType::Gl => {
+                    let mut result = [0];
+                    unsafe {
+                        self.get_integer_v(ffi::PROVOKING_VERTEX, &mut result);
+                    }
+                    result[0] as GLenum
+                },
+                GlType::Gles => ffi::LAST_VERTEX_CONVENTION,
+            }
+        }
+
+        pub fn cull_face(&self, mode: GLenum) {
+            forward_debug!(self.cull_face(mode));
             match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.CullFace(mode) },
+                Gl::Gles(gles) => unsafe { gles.CullFace(mode) },
+                _ => self.record(GlCall::Other("cull_face")),
+            }
+        }
+
+        pub fn front_face(&self, mode: GLenum) {
+            forward_debug!(self.front_face(mode));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.FrontFace(mode) },
+                Gl::Gles(gles) => unsafe { gles.FrontFace(mode) },
+                _ => self.record(GlCall::Other("front_face")),
+            }
+        }
+
+        pub fn depth_func(&self, func: GLenum) {
+            forward_debug!(self.depth_func(func));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.DepthFunc(func) },
                 Gl::Gles(gles) => unsafe { gles.DepthFunc(func) },
+                _ => self.record(GlCall::DepthFunc(func)),
             }
         }
 
         pub fn depth_mask(&self, flag: bool) {
+            forward_debug!(self.depth_mask(flag));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.DepthMask(flag as GLboolean) },
                 Gl::Gles(gles) => unsafe { gles.DepthMask(flag as GLboolean) },
+                _ => self.record(GlCall::DepthMask(flag)),
             }
         }
 
         pub fn depth_range(&self, near: f64, far: f64) {
+            forward_debug!(self.depth_range(near, far));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.DepthRange(near, far) },
                 Gl::Gles(gles) => unsafe { gles.DepthRangef(near as f32, far as f32) },
+                _ => self.record(GlCall::Other("depth_range")),
+            }
+        }
+
+        /// Single-precision `depth_range`. Desktop GL without
+        /// GL_ARB_ES2_compatibility falls back to the double entry point.
+        pub fn depth_range_f(&self, near: f32, far: f32) {
+            forward_debug!(self.depth_range_f(near, far));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.DepthRangef.is_loaded() {
+                        unsafe { gl.DepthRangef(near, far) }
+                    } else {
+                        unsafe { gl.DepthRange(near as f64, far as f64) }
+                    }
+                },
+                Gl::Gles(gles) => unsafe { gles.DepthRangef(near, far) },
+                _ => self.record(GlCall::Other("depth_range_f")),
+            }
+        }
+
+        /// Requires GL_EXT_depth_bounds_test; a no-op when it is unavailable,
+        /// which is always the case on GLES.
+        pub fn depth_bounds(&self, zmin: f64, zmax: f64) {
+            forward_debug!(self.depth_bounds(zmin, zmax));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.DepthBoundsEXT.is_loaded() {
+                        unsafe { gl.DepthBoundsEXT(zmin, zmax) }
+                    }
+                },
+                Gl::Gles(..) => {},
+                _ => self.record(GlCall::Other("depth_bounds")),
             }
         }
 
         pub fn line_width(&self, width: GLfloat) {
+            forward_debug!(self.line_width(width));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.LineWidth(width) },
                 Gl::Gles(gles) => unsafe { gles.LineWidth(width) },
+                _ => self.record(GlCall::Other("line_width")),
+            }
+        }
+
+        /// Sets the size of rasterized points when PROGRAM_POINT_SIZE is
+        /// disabled. GLES always takes it from gl_PointSize, so this is a no-op
+        /// there.
+        pub fn point_size(&self, size: GLfloat) {
+            forward_debug!(self.point_size(size));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.PointSize(size) },
+                Gl::Gles(..) => {},
+                _ => self.record(GlCall::Other("point_size")),
+            }
+        }
+
+        /// Requires GL 4.0/GL_ARB_tessellation_shader or GLES 3.2; a no-op
+        /// otherwise.
+        pub fn patch_parameter_i(&self, pname: GLenum, value: GLint) {
+            forward_debug!(self.patch_parameter_i(pname, value));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.PatchParameteri.is_loaded() {
+                        unsafe { gl.PatchParameteri(pname, value) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.PatchParameteri.is_loaded() {
+                        unsafe { gles.PatchParameteri(pname, value) }
+                    }
+                },
+                _ => self.record(GlCall::Other("patch_parameter_i")),
             }
         }
 
         pub fn polygon_offset(&self, factor: GLfloat, units: GLfloat) {
+            forward_debug!(self.polygon_offset(factor, units));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.PolygonOffset(factor, units) },
                 Gl::Gles(gles) => unsafe { gles.PolygonOffset(factor, units) },
+                _ => self.record(GlCall::Other("polygon_offset")),
+            }
+        }
+
+        /// Selects how polygons are rasterized, e.g. LINE for wireframe.
+        /// GLES has no polygon mode, so this is a no-op there.
+        pub fn polygon_mode(&self, face: GLenum, mode: GLenum) {
+            forward_debug!(self.polygon_mode(face, mode));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.PolygonMode(face, mode) },
+                Gl::Gles(..) => {},
+                _ => self.record(GlCall::Other("polygon_mode")),
             }
         }
 
         pub fn sample_coverage(&self, value: GLclampf, invert: bool) {
+            forward_debug!(self.sample_coverage(value, invert));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.SampleCoverage(value, invert as GLboolean) },
                 Gl::Gles(gles) => unsafe { gles.SampleCoverage(value, invert as GLboolean) },
+                _ => self.record(GlCall::Other("sample_coverage")),
+            }
+        }
+
+        /// Returns the location of sample `index` within a pixel, for
+        /// `pname` SAMPLE_POSITION.
+        pub fn get_multisample_fv(&self, pname: GLenum, index: GLuint) -> [GLfloat; 2] {
+            forward_debug!(self.get_multisample_fv(pname, index));
+            let mut result = [0.; 2];
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.GetMultisamplefv(pname, index, result.as_mut_ptr()) },
+                Gl::Gles(gles) => {
+                    if gles.GetMultisamplefv.is_loaded() {
+                        unsafe { gles.GetMultisamplefv(pname, index, result.as_mut_ptr()) }
+                    }
+                },
+                _ => self.record(GlCall::Other("get_multisample_fv")),
+            }
+            result
+        }
+
+        pub fn sample_maski(&self, mask_number: GLuint, mask: GLbitfield) {
+            forward_debug!(self.sample_maski(mask_number, mask));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.SampleMaski(mask_number, mask) },
+                Gl::Gles(gles) => {
+                    if gles.SampleMaski.is_loaded() {
+                        unsafe { gles.SampleMaski(mask_number, mask) }
+                    }
+                },
+                _ => self.record(GlCall::Other("sample_maski")),
+            }
+        }
+
+        /// Sets the minimum fraction of samples to shade individually when
+        /// SAMPLE_SHADING is enabled. A no-op where unsupported.
+        pub fn min_sample_shading(&self, value: GLclampf) {
+            forward_debug!(self.min_sample_shading(value));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.MinSampleShadingARB.is_loaded() {
+                        unsafe { gl.MinSampleShadingARB(value) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.MinSampleShading.is_loaded() {
+                        unsafe { gles.MinSampleShading(value) }
+                    }
+                },
+                _ => self.record(GlCall::Other("min_sample_shading")),
             }
         }
 
         pub fn clear_color(&self, r: f32, g: f32, b: f32, a: f32) {
+            forward_debug!(self.clear_color(r, g, b, a));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.ClearColor(r, g, b, a) },
                 Gl::Gles(gles) => unsafe { gles.ClearColor(r, g, b, a) },
+                _ => self.record(GlCall::ClearColor(r, g, b, a)),
             }
         }
 
         pub fn clear_depth(&self, depth: f64) {
+            forward_debug!(self.clear_depth(depth));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.ClearDepth(depth) },
                 Gl::Gles(gles) => unsafe { gles.ClearDepthf(depth as f32) },
+                _ => self.record(GlCall::Other("clear_depth")),
+            }
+        }
+
+        /// Single-precision `clear_depth`. Desktop GL without
+        /// GL_ARB_ES2_compatibility falls back to the double entry point.
+        pub fn clear_depth_f(&self, depth: f32) {
+            forward_debug!(self.clear_depth_f(depth));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ClearDepthf.is_loaded() {
+                        unsafe { gl.ClearDepthf(depth) }
+                    } else {
+                        unsafe { gl.ClearDepth(depth as f64) }
+                    }
+                },
+                Gl::Gles(gles) => unsafe { gles.ClearDepthf(depth) },
+                _ => self.record(GlCall::Other("clear_depth_f")),
             }
         }
 
         pub fn clear_stencil(&self, s: GLint) {
+            forward_debug!(self.clear_stencil(s));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.ClearStencil(s) },
                 Gl::Gles(gles) => unsafe { gles.ClearStencil(s) },
+                _ => self.record(GlCall::Other("clear_stencil")),
             }
         }
 
         pub fn clear(&self, buffer_mask: GLbitfield) {
+            forward_debug!(self.clear(buffer_mask));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Clear(buffer_mask) },
                 Gl::Gles(gles) => unsafe { gles.Clear(buffer_mask) },
+                _ => self.record(GlCall::Clear(buffer_mask)),
             }
         }
 
         pub fn scissor(&self, x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+            forward_debug!(self.scissor(x, y, width, height));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Scissor(x, y, width, height) },
                 Gl::Gles(gles) => unsafe { gles.Scissor(x, y, width, height) },
+                _ => self.record(GlCall::Scissor(x, y, width, height)),
             }
         }
 
         pub fn stencil_op(&self, sfail: GLenum, dpfail: GLenum, dppass: GLenum) {
+            forward_debug!(self.stencil_op(sfail, dpfail, dppass));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.StencilOp(sfail, dpfail, dppass) },
                 Gl::Gles(gles) => unsafe { gles.StencilOp(sfail, dpfail, dppass) },
+                _ => self.record(GlCall::Other("stencil_op")),
             }
         }
 
@@ -775,79 +2560,182 @@ pub mod gl {
             dpfail: GLenum,
             dppass: GLenum,
         ) {
+            forward_debug!(self.stencil_op_separate(face, sfail, dpfail, dppass));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.StencilOpSeparate(face, sfail, dpfail, dppass) },
                 Gl::Gles(gles) => unsafe { gles.StencilOpSeparate(face, sfail, dpfail, dppass) },
+                _ => self.record(GlCall::Other("stencil_op_separate")),
             }
         }
 
         pub fn stencil_mask(&self, mask: GLuint) {
+            forward_debug!(self.stencil_mask(mask));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.StencilMask(mask) },
                 Gl::Gles(gles) => unsafe { gles.StencilMask(mask) },
+                _ => self.record(GlCall::Other("stencil_mask")),
             }
         }
 
         pub fn stencil_mask_separate(&self, face: GLenum, mask: GLuint) {
+            forward_debug!(self.stencil_mask_separate(face, mask));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.StencilMaskSeparate(face, mask) },
                 Gl::Gles(gles) => unsafe { gles.StencilMaskSeparate(face, mask) },
+                _ => self.record(GlCall::Other("stencil_mask_separate")),
             }
         }
 
         pub fn stencil_func(&self, func: GLenum, ref_: GLint, mask: GLuint) {
+            forward_debug!(self.stencil_func(func, ref_, mask));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.StencilFunc(func, ref_, mask) },
                 Gl::Gles(gles) => unsafe { gles.StencilFunc(func, ref_, mask) },
+                _ => self.record(GlCall::Other("stencil_func")),
             }
         }
 
         pub fn stencil_func_separate(&self, face: GLenum, func: GLenum, ref_: GLint, mask: GLuint) {
+            forward_debug!(self.stencil_func_separate(face, func, ref_, mask));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.StencilFuncSeparate(face, func, ref_, mask) },
                 Gl::Gles(gles) => unsafe { gles.StencilFuncSeparate(face, func, ref_, mask) },
+                _ => self.record(GlCall::Other("stencil_func_separate")),
             }
         }
 
         pub fn is_enabled(&self, cap: GLenum) -> bool {
+            forward_debug!(self.is_enabled(cap));
             TRUE == match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.IsEnabled(cap) },
                 Gl::Gles(gles) => unsafe { gles.IsEnabled(cap) },
+                _ => {
+                    self.record(GlCall::Other("is_enabled"));
+                    FALSE
+                },
             }
         }
 
         pub fn enable(&self, cap: GLenum) {
+            forward_debug!(self.enable(cap));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Enable(cap) },
                 Gl::Gles(gles) => unsafe { gles.Enable(cap) },
+                _ => self.record(GlCall::Enable(cap)),
             }
         }
 
         pub fn disable(&self, cap: GLenum) {
+            forward_debug!(self.disable(cap));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Disable(cap) },
                 Gl::Gles(gles) => unsafe { gles.Disable(cap) },
+                _ => self.record(GlCall::Disable(cap)),
+            }
+        }
+
+        pub fn is_enabled_i(&self, cap: GLenum, index: GLuint) -> bool {
+            forward_debug!(self.is_enabled_i(cap, index));
+            TRUE == match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.IsEnabledi(cap, index) },
+                Gl::Gles(gles) if gles.IsEnabledi.is_loaded() => unsafe {
+                    gles.IsEnabledi(cap, index)
+                },
+                Gl::Gles(..) => FALSE,
+                _ => {
+                    self.record(GlCall::Other("is_enabled_i"));
+                    FALSE
+                },
+            }
+        }
+
+        pub fn enable_i(&self, cap: GLenum, index: GLuint) {
+            forward_debug!(self.enable_i(cap, index));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.Enablei(cap, index) },
+                Gl::Gles(gles) => {
+                    if gles.Enablei.is_loaded() {
+                        unsafe { gles.Enablei(cap, index) }
+                    }
+                },
+                _ => self.record(GlCall::Other("enable_i")),
+            }
+        }
+
+        pub fn disable_i(&self, cap: GLenum, index: GLuint) {
+            forward_debug!(self.disable_i(cap, index));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.Disablei(cap, index) },
+                Gl::Gles(gles) => {
+                    if gles.Disablei.is_loaded() {
+                        unsafe { gles.Disablei(cap, index) }
+                    }
+                },
+                _ => self.record(GlCall::Other("disable_i")),
+            }
+        }
+
+        pub fn set_rasterizer_discard(&self, enabled: bool) {
+            if enabled {
+                self.enable(ffi::RASTERIZER_DISCARD);
+            } else {
+                self.disable(ffi::RASTERIZER_DISCARD);
             }
         }
 
+        pub fn is_rasterizer_discard_enabled(&self) -> bool {
+            self.is_enabled(ffi::RASTERIZER_DISCARD)
+        }
+
         pub fn finish(&self) {
+            forward_debug!(self.finish());
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Finish() },
                 Gl::Gles(gles) => unsafe { gles.Finish() },
+                _ => self.record(GlCall::Other("finish")),
             }
         }
 
         pub fn flush(&self) {
+            forward_debug!(self.flush());
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Flush() },
                 Gl::Gles(gles) => unsafe { gles.Flush() },
+                _ => self.record(GlCall::Other("flush")),
             }
         }
 
         pub fn get_string(&self, which: GLenum) -> String {
+            forward_debug!(self.get_string(which));
             let llstr = match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.GetString(which) },
                 Gl::Gles(gles) => unsafe { gles.GetString(which) },
+                Gl::Mock(state) => {
+                    return state
+                        .strings
+                        .borrow()
+                        .get(&which)
+                        .cloned()
+                        .unwrap_or_default()
+                },
+                _ => {
+                    self.record(GlCall::Other("get_string"));
+                    ptr::null()
+                },
             };
             if !llstr.is_null() {
                 unsafe {
@@ -859,10 +2747,85 @@ pub mod gl {
             }
         }
 
+        /// Parses the major and minor version out of the VERSION string, which
+        /// looks like "4.6.0 NVIDIA 535.54" on desktop and "OpenGL ES 3.2
+        /// Mesa 23.0" on GLES. Returns (0, 0) if it can't be parsed.
+        pub fn get_version(&self) -> (u32, u32) {
+            let version = self.get_string(ffi::VERSION);
+            let number = version
+                .split_whitespace()
+                .find(|word| word.starts_with(|c: char| c.is_ascii_digit()))
+                .unwrap_or("");
+            let mut parts = number.split('.').map(|part| {
+                let digits = part
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(part.len());
+                part[..digits].parse::<u32>().ok()
+            });
+            match (parts.next(), parts.next()) {
+                (Some(Some(major)), Some(Some(minor))) => (major, minor),
+                _ => (0, 0),
+            }
+        }
+
+        pub fn renderer(&self) -> String {
+            self.get_string(ffi::RENDERER)
+        }
+
+        pub fn vendor(&self) -> String {
+            self.get_string(ffi::VENDOR)
+        }
+
+        /// Returns the flags the context was created with. CONTEXT_FLAGS is
+        /// not available before GLES 3.2, so GLES contexts report no flags.
+        pub fn context_flags(&self) -> ContextFlags {
+            match self.get_type() {
+                GlType::Gl => {
+                    let mut flags = [0];
+                    unsafe {
+                        self.get_integer_v(ffi::CONTEXT_FLAGS, &mut flags);
+                    }
+                    ContextFlags::from_gl(flags[0] as GLenum)
+                },
+                GlType::Gles => ContextFlags::default(),
+            }
+        }
+
+        /// Whether this is a desktop GL core profile context, in which a
+        /// non-zero vertex array object must be bound for drawing.
+        pub fn is_core_profile(&self) -> bool {
+            let cached = self
+                .context_state()
+                .and_then(|state| state.core_profile.get());
+            if let Some(core_profile) = cached {
+                return core_profile;
+            }
+            let core_profile = match self.get_type() {
+                GlType::Gl => {
+                    let mut mask = [0];
+                    unsafe {
+                        self.get_integer_v(ffi::CONTEXT_PROFILE_MASK, &mut mask);
+                    }
+                    mask[0] as GLenum & ffi::CONTEXT_CORE_PROFILE_BIT != 0
+                },
+                GlType::Gles => false,
+            };
+            if let Some(state) = self.context_state() {
+                state.core_profile.set(Some(core_profile));
+            }
+            core_profile
+        }
+
         pub fn get_string_i(&self, which: GLenum, index: GLuint) -> String {
+            forward_debug!(self.get_string_i(which, index));
             let llstr = match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.GetStringi(which, index) },
                 Gl::Gles(gles) => unsafe { gles.GetStringi(which, index) },
+                _ => {
+                    self.record(GlCall::Other("get_string_i"));
+                    ptr::null()
+                },
             };
             if !llstr.is_null() {
                 unsafe {
@@ -874,11 +2837,44 @@ pub mod gl {
             }
         }
 
+        /// Returns the names of the supported extensions, read one at a time
+        /// with `get_string_i` where that is available, and otherwise from the
+        /// EXTENSIONS string, which core profiles reject with INVALID_ENUM.
+        pub fn extensions(&self) -> HashSet<String> {
+            if self.has_get_string_i() {
+                let count = self.get_integer(ffi::NUM_EXTENSIONS);
+                return (0..count.max(0) as GLuint)
+                    .map(|i| self.get_string_i(ffi::EXTENSIONS, i))
+                    .collect();
+            }
+            self.get_string(ffi::EXTENSIONS)
+                .split_whitespace()
+                .map(str::to_string)
+                .collect()
+        }
+
+        fn has_get_string_i(&self) -> bool {
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => gl.GetStringi.is_loaded(),
+                Gl::Gles(gles) => gles.GetStringi.is_loaded(),
+                Gl::Debug(inner, _) => inner.has_get_string_i(),
+                _ => false,
+            }
+        }
+
+        pub fn has_extension(&self, name: &str) -> bool {
+            self.extensions().contains(name)
+        }
+
         pub unsafe fn get_shader_iv(&self, shader: GLuint, pname: GLenum, result: &mut [GLint]) {
+            forward_debug!(self.get_shader_iv(shader, pname, result));
             assert!(!result.is_empty());
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => gl.GetShaderiv(shader, pname, result.as_mut_ptr()),
                 Gl::Gles(gles) => gles.GetShaderiv(shader, pname, result.as_mut_ptr()),
+                _ => self.record(GlCall::Other("get_shader_iv")),
             }
         }
 
@@ -887,26 +2883,8 @@ pub mod gl {
             shader_type: GLuint,
             precision_type: GLuint,
         ) -> (GLint, GLint, GLint) {
+            forward_debug!(self.get_shader_precision_format(shader_type, precision_type));
             match self {
-                Gl::Gl(..) => {
-                    // gl.GetShaderPrecisionFormat is not available until OpenGL 4.1.
-                    // Fall back to OpenGL standard precision that most desktop hardware support.
-                    match precision_type {
-                        ffi::LOW_FLOAT | ffi::MEDIUM_FLOAT | ffi::HIGH_FLOAT => {
-                            // Fallback to IEEE 754 single precision
-                            // Range: from -2^127 to 2^127
-                            // Significand precision: 23 bits
-                            (127, 127, 23)
-                        }
-                        ffi::LOW_INT | ffi::MEDIUM_INT | ffi::HIGH_INT => {
-                            // Fallback to single precision integer
-                            // Range: from -2^24 to 2^24
-                            // Precision: For integer formats this value is always 0
-                            (24, 24, 0)
-                        }
-                        _ => (0, 0, 0),
-                    }
-                }
                 Gl::Gles(gles) => {
                     let (mut range, mut precision) = match precision_type {
                         // These values are for a 32-bit twos-complement integer format.
@@ -931,13 +2909,35 @@ pub mod gl {
                     }
                     (range[0], range[1], precision)
                 }
+                _ => {
+                    // gl.GetShaderPrecisionFormat is not available until OpenGL 4.1.
+                    // Fall back to OpenGL standard precision that most desktop hardware support.
+                    match precision_type {
+                        ffi::LOW_FLOAT | ffi::MEDIUM_FLOAT | ffi::HIGH_FLOAT => {
+                            // Fallback to IEEE 754 single precision
+                            // Range: from -2^127 to 2^127
+                            // Significand precision: 23 bits
+                            (127, 127, 23)
+                        }
+                        ffi::LOW_INT | ffi::MEDIUM_INT | ffi::HIGH_INT => {
+                            // Fallback to single precision integer
+                            // Range: from -2^24 to 2^24
+                            // Precision: For integer formats this value is always 0
+                            (24, 24, 0)
+                        }
+                        _ => (0, 0, 0),
+                    }
+                }
             }
         }
 
         pub fn viewport(&self, x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+            forward_debug!(self.viewport(x, y, width, height));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Viewport(x, y, width, height) },
                 Gl::Gles(gles) => unsafe { gles.Viewport(x, y, width, height) },
+                _ => self.record(GlCall::Viewport(x, y, width, height)),
             }
         }
 
@@ -947,14 +2947,17 @@ pub mod gl {
             attachment: GLenum,
             pname: GLenum,
         ) -> GLint {
+            forward_debug!(self.get_framebuffer_attachment_parameter_iv(target, attachment, pname));
             let mut result = 0;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.GetFramebufferAttachmentParameteriv(target, attachment, pname, &mut result)
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.GetFramebufferAttachmentParameteriv(target, attachment, pname, &mut result)
                 },
+                _ => self.record(GlCall::Other("get_framebuffer_attachment_parameter_iv")),
             }
             result
         }
@@ -966,106 +2969,197 @@ pub mod gl {
             pname: GLenum,
             result: &mut [GLint],
         ) {
+            forward_debug!(self.get_internal_format_iv(target, internalformat, pname, result));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.GetInternalformativ(target, internalformat, pname, result.len() as _, result.as_mut_ptr())
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.GetInternalformativ(target, internalformat, pname, result.len() as _, result.as_mut_ptr())
                 },
+                _ => self.record(GlCall::Other("get_internal_format_iv")),
+            }
+        }
+
+        /// Reports whether 2D textures of `internal_format` are supported,
+        /// color-renderable and filterable. Uses GL_ARB_internalformat_query2
+        /// when it's available, and the core format tables otherwise.
+        pub fn format_capabilities(&self, internal_format: GLenum) -> FormatCaps {
+            forward_debug!(self.format_capabilities(internal_format));
+            if !self.has_internalformat_query2() {
+                return known_format_capabilities(self.get_type(), internal_format);
+            }
+            let query = |pname| {
+                let mut result = [0];
+                self.get_internal_format_iv(ffi::TEXTURE_2D, internal_format, pname, &mut result);
+                result[0] as GLenum
+            };
+            FormatCaps {
+                supported: query(ffi::INTERNALFORMAT_SUPPORTED) == TRUE as GLenum,
+                color_renderable: query(ffi::COLOR_RENDERABLE) == TRUE as GLenum,
+                filterable: query(ffi::FILTER) != ffi::NONE,
+            }
+        }
+
+        /// Whether GL_ARB_internalformat_query2 can be used, which is looked
+        /// up once per context as it walks the extension list.
+        fn has_internalformat_query2(&self) -> bool {
+            let cached = self
+                .context_state()
+                .and_then(|state| state.internalformat_query2.get());
+            if let Some(query2) = cached {
+                return query2;
+            }
+            let query2 = match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    gl.GetInternalformativ.is_loaded()
+                        && self.has_extension("GL_ARB_internalformat_query2")
+                },
+                _ => false,
+            };
+            if let Some(state) = self.context_state() {
+                state.internalformat_query2.set(Some(query2));
             }
+            query2
         }
 
         pub fn get_renderbuffer_parameter_iv(&self, target: GLenum, pname: GLenum) -> GLint {
+            forward_debug!(self.get_renderbuffer_parameter_iv(target, pname));
             let mut result = 0;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.GetRenderbufferParameteriv(target, pname, &mut result) },
                 Gl::Gles(gles) => unsafe {
                     gles.GetRenderbufferParameteriv(target, pname, &mut result)
                 },
+                _ => self.record(GlCall::Other("get_renderbuffer_parameter_iv")),
             }
             result
         }
 
         pub fn delete_renderbuffers(&self, buffers: &[GLuint]) {
+            forward_debug!(self.delete_renderbuffers(buffers));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.DeleteRenderbuffers(buffers.len() as GLsizei, buffers.as_ptr())
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.DeleteRenderbuffers(buffers.len() as GLsizei, buffers.as_ptr())
                 },
+                _ => self.record(GlCall::Other("delete_renderbuffers")),
             }
         }
 
         pub fn delete_textures(&self, textures: &[GLuint]) {
+            forward_debug!(self.delete_textures(textures));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.DeleteTextures(textures.len() as GLsizei, textures.as_ptr())
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.DeleteTextures(textures.len() as GLsizei, textures.as_ptr())
                 },
+                _ => self.record(GlCall::Other("delete_textures")),
             }
         }
 
         pub fn delete_framebuffers(&self, framebuffers: &[GLuint]) {
+            forward_debug!(self.delete_framebuffers(framebuffers));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.DeleteFramebuffers(framebuffers.len() as GLsizei, framebuffers.as_ptr())
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.DeleteFramebuffers(framebuffers.len() as GLsizei, framebuffers.as_ptr())
                 },
+                _ => self.record(GlCall::Other("delete_framebuffers")),
             }
         }
 
         pub fn bind_renderbuffer(&self, target: GLenum, renderbuffer: GLuint) {
+            forward_debug!(self.bind_renderbuffer(target, renderbuffer));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.BindRenderbuffer(target, renderbuffer) },
                 Gl::Gles(gles) => unsafe { gles.BindRenderbuffer(target, renderbuffer) },
+                _ => self.record(GlCall::BindRenderbuffer(target, renderbuffer)),
             }
         }
 
         pub fn is_renderbuffer(&self, renderbuffer: GLuint) -> bool {
+            forward_debug!(self.is_renderbuffer(renderbuffer));
             TRUE == match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.IsRenderbuffer(renderbuffer) },
                 Gl::Gles(gles) => unsafe { gles.IsRenderbuffer(renderbuffer) },
+                _ => {
+                    self.record(GlCall::Other("is_renderbuffer"));
+                    FALSE
+                },
             }
         }
 
         pub fn bind_framebuffer(&self, target: GLenum, framebuffer: GLuint) {
+            forward_debug!(self.bind_framebuffer(target, framebuffer));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.BindFramebuffer(target, framebuffer) },
                 Gl::Gles(gles) => unsafe { gles.BindFramebuffer(target, framebuffer) },
+                _ => self.record(GlCall::BindFramebuffer(target, framebuffer)),
             }
         }
 
         pub fn is_framebuffer(&self, framebuffer: GLuint) -> bool {
+            forward_debug!(self.is_framebuffer(framebuffer));
             TRUE == match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.IsFramebuffer(framebuffer) },
                 Gl::Gles(gles) => unsafe { gles.IsFramebuffer(framebuffer) },
+                _ => {
+                    self.record(GlCall::Other("is_framebuffer"));
+                    FALSE
+                },
             }
         }
 
         pub fn bind_texture(&self, target: GLenum, texture: GLuint) {
+            forward_debug!(self.bind_texture(target, texture));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.BindTexture(target, texture) },
                 Gl::Gles(gles) => unsafe { gles.BindTexture(target, texture) },
+                _ => self.record(GlCall::BindTexture(target, texture)),
             }
         }
 
         pub fn is_texture(&self, texture: GLuint) -> bool {
+            forward_debug!(self.is_texture(texture));
             TRUE == match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.IsTexture(texture) },
                 Gl::Gles(gles) => unsafe { gles.IsTexture(texture) },
+                _ => {
+                    self.record(GlCall::Other("is_texture"));
+                    FALSE
+                },
             }
         }
 
         pub fn is_shader(&self, shader: GLuint) -> bool {
+            forward_debug!(self.is_shader(shader));
             TRUE == match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.IsShader(shader) },
                 Gl::Gles(gles) => unsafe { gles.IsShader(shader) },
+                _ => {
+                    self.record(GlCall::Other("is_shader"));
+                    FALSE
+                },
             }
         }
 
@@ -1076,44 +3170,172 @@ pub mod gl {
             data: *const GLvoid,
             usage: GLenum,
         ) {
+            forward_debug!(self.buffer_data(target, size, data, usage));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => gl.BufferData(target, size, data, usage),
                 Gl::Gles(gles) => gles.BufferData(target, size, data, usage),
+                _ => self.record(GlCall::Other("buffer_data")),
             }
         }
 
-        pub unsafe fn buffer_sub_data(
+        /// Allocates immutable storage of `size` bytes for the buffer bound to
+        /// `target`, initialized from `data` if given. A no-op without
+        /// GL_ARB_buffer_storage or GL_EXT_buffer_storage.
+        pub fn buffer_storage(
             &self,
             target: GLenum,
-            offset: isize,
             size: GLsizeiptr,
-            data: *const GLvoid,
+            data: Option<&[u8]>,
+            flags: GLbitfield,
         ) {
+            forward_debug!(self.buffer_storage(target, size, data, flags));
+            let data = match data {
+                Some(data) => {
+                    assert_eq!(data.len(), size as usize);
+                    data.as_ptr() as *const GLvoid
+                },
+                None => ptr::null(),
+            };
             match self {
-                Gl::Gl(gl) => gl.BufferSubData(target, offset, size, data),
-                Gl::Gles(gles) => gles.BufferSubData(target, offset, size, data),
-            }
-        }
-
-        pub fn read_buffer(&self, buffer: GLenum) {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.BufferStorage.is_loaded() {
+                        unsafe { gl.BufferStorage(target, size, data, flags) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.BufferStorageEXT.is_loaded() {
+                        unsafe { gles.BufferStorageEXT(target, size, data, flags) }
+                    }
+                },
+                _ => self.record(GlCall::Other("buffer_storage")),
+            }
+        }
+
+        pub unsafe fn buffer_sub_data(
+            &self,
+            target: GLenum,
+            offset: isize,
+            size: GLsizeiptr,
+            data: *const GLvoid,
+        ) {
+            forward_debug!(self.buffer_sub_data(target, offset, size, data));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => gl.BufferSubData(target, offset, size, data),
+                Gl::Gles(gles) => gles.BufferSubData(target, offset, size, data),
+                _ => self.record(GlCall::Other("buffer_sub_data")),
+            }
+        }
+
+        pub fn read_buffer(&self, buffer: GLenum) {
+            forward_debug!(self.read_buffer(buffer));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.ReadBuffer(buffer) },
                 Gl::Gles(gles) => unsafe { gles.ReadBuffer(buffer) },
+                _ => self.record(GlCall::Other("read_buffer")),
             }
         }
 
         pub fn draw_buffers(&self, bufs: &[GLenum]) {
+            forward_debug!(self.draw_buffers(bufs));
             let len = bufs.len() as GLsizei;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.DrawBuffers(len, bufs.as_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.DrawBuffers(len, bufs.as_ptr()) },
+                _ => self.record(GlCall::Other("draw_buffers")),
+            }
+        }
+
+        /// Selects a single color buffer to draw into. GLES has no
+        /// glDrawBuffer, so there this goes through glDrawBuffers.
+        pub fn draw_buffer(&self, buf: GLenum) {
+            forward_debug!(self.draw_buffer(buf));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.DrawBuffer(buf) },
+                Gl::Gles(gles) => unsafe { gles.DrawBuffers(1, &buf) },
+                _ => self.record(GlCall::Other("draw_buffer")),
             }
         }
 
         pub fn draw_arrays(&self, mode: GLenum, first: GLint, count: GLsizei) {
+            forward_debug!(self.draw_arrays(mode, first, count));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.DrawArrays(mode, first, count) },
                 Gl::Gles(gles) => unsafe { gles.DrawArrays(mode, first, count) },
+                _ => self.record(GlCall::DrawArrays(mode, first, count)),
+            }
+        }
+
+        /// Draws vertices generated entirely in the shader (e.g. from
+        /// gl_VertexID). Core profile desktop contexts refuse to draw without a
+        /// vertex array object, so when none is bound an empty one, created on
+        /// first use and deleted along with the `Gl`, is bound for the
+        /// duration of the draw. Other contexts allow drawing with the default
+        /// vertex array, so nothing extra happens there.
+        pub fn draw_arrays_attributeless(&self, mode: GLenum, first: GLint, count: GLsizei) {
+            let state = match self.context_state() {
+                Some(state) if self.is_core_profile() => state,
+                _ => return self.draw_arrays(mode, first, count),
+            };
+            if self.get_integer(ffi::VERTEX_ARRAY_BINDING) != 0 {
+                return self.draw_arrays(mode, first, count);
+            }
+            if state.attributeless_vertex_array.get() == 0 {
+                let vao = self.gen_vertex_arrays(1)[0];
+                state.attributeless_vertex_array.set(vao);
+            }
+            self.bind_vertex_array(state.attributeless_vertex_array.get());
+            self.draw_arrays(mode, first, count);
+            self.bind_vertex_array(0);
+        }
+
+        /// Uses GL_EXT_multi_draw_arrays on GLES, falling back to a sequence of
+        /// `draw_arrays` calls when it is unavailable.
+        pub fn multi_draw_arrays(&self, mode: GLenum, firsts: &[GLint], counts: &[GLsizei]) {
+            forward_debug!(self.multi_draw_arrays(mode, firsts, counts));
+            assert_eq!(firsts.len(), counts.len());
+            let len = firsts.len() as GLsizei;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.MultiDrawArrays(mode, firsts.as_ptr(), counts.as_ptr(), len)
+                },
+                Gl::Gles(gles) if gles.MultiDrawArraysEXT.is_loaded() => unsafe {
+                    gles.MultiDrawArraysEXT(mode, firsts.as_ptr(), counts.as_ptr(), len)
+                },
+                _ => {
+                    for (&first, &count) in firsts.iter().zip(counts) {
+                        self.draw_arrays(mode, first, count);
+                    }
+                },
+            }
+        }
+
+        /// Draws using parameters read from the buffer bound to
+        /// DRAW_INDIRECT_BUFFER at byte offset `indirect_offset`. Requires
+        /// GL 4.0/GL_ARB_draw_indirect or GLES 3.1; a no-op otherwise.
+        pub fn draw_arrays_indirect(&self, mode: GLenum, indirect_offset: GLuint) {
+            forward_debug!(self.draw_arrays_indirect(mode, indirect_offset));
+            let indirect = indirect_offset as *const c_void;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.DrawArraysIndirect.is_loaded() {
+                        unsafe { gl.DrawArraysIndirect(mode, indirect) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.DrawArraysIndirect.is_loaded() {
+                        unsafe { gles.DrawArraysIndirect(mode, indirect) }
+                    }
+                },
+                _ => self.record(GlCall::Other("draw_arrays_indirect")),
             }
         }
 
@@ -1124,11 +3346,14 @@ pub mod gl {
             count: GLsizei,
             primcount: GLsizei,
         ) {
+            forward_debug!(self.draw_arrays_instanced(mode, first, count, primcount));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.DrawArraysInstanced(mode, first, count, primcount) },
                 Gl::Gles(gles) => unsafe {
                     gles.DrawArraysInstanced(mode, first, count, primcount)
                 },
+                _ => self.record(GlCall::DrawArraysInstanced(mode, first, count, primcount)),
             }
         }
 
@@ -1139,13 +3364,345 @@ pub mod gl {
             element_type: GLenum,
             indices_offset: GLuint,
         ) {
+            forward_debug!(self.draw_elements(mode, count, element_type, indices_offset));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.DrawElements(mode, count, element_type, indices_offset as *const c_void)
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.DrawElements(mode, count, element_type, indices_offset as *const c_void)
                 },
+                _ => self.record(GlCall::DrawElements(
+                    mode,
+                    count,
+                    element_type,
+                    indices_offset,
+                )),
+            }
+        }
+
+        /// Uses GL_EXT_multi_draw_arrays on GLES, falling back to a sequence of
+        /// `draw_elements` calls when it is unavailable.
+        pub fn multi_draw_elements(
+            &self,
+            mode: GLenum,
+            counts: &[GLsizei],
+            element_type: GLenum,
+            indices_offsets: &[GLuint],
+        ) {
+            forward_debug!(self.multi_draw_elements(mode, counts, element_type, indices_offsets));
+            assert_eq!(counts.len(), indices_offsets.len());
+            let len = counts.len() as GLsizei;
+            let indices: Vec<*const c_void> = indices_offsets
+                .iter()
+                .map(|&offset| offset as *const c_void)
+                .collect();
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.MultiDrawElements(mode, counts.as_ptr(), element_type, indices.as_ptr(), len)
+                },
+                Gl::Gles(gles) if gles.MultiDrawElementsEXT.is_loaded() => unsafe {
+                    gles.MultiDrawElementsEXT(
+                        mode,
+                        counts.as_ptr(),
+                        element_type,
+                        indices.as_ptr(),
+                        len,
+                    )
+                },
+                _ => {
+                    for (&count, &offset) in counts.iter().zip(indices_offsets) {
+                        self.draw_elements(mode, count, element_type, offset);
+                    }
+                },
+            }
+        }
+
+        /// Draws using parameters read from the buffer bound to
+        /// DRAW_INDIRECT_BUFFER at byte offset `indirect_offset`. Requires
+        /// GL 4.0/GL_ARB_draw_indirect or GLES 3.1; a no-op otherwise.
+        pub fn draw_elements_indirect(
+            &self,
+            mode: GLenum,
+            element_type: GLenum,
+            indirect_offset: GLuint,
+        ) {
+            forward_debug!(self.draw_elements_indirect(mode, element_type, indirect_offset));
+            let indirect = indirect_offset as *const c_void;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.DrawElementsIndirect.is_loaded() {
+                        unsafe { gl.DrawElementsIndirect(mode, element_type, indirect) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.DrawElementsIndirect.is_loaded() {
+                        unsafe { gles.DrawElementsIndirect(mode, element_type, indirect) }
+                    }
+                },
+                _ => self.record(GlCall::Other("draw_elements_indirect")),
+            }
+        }
+
+        /// Runs the current program's compute shader over a grid of work
+        /// groups. A no-op where compute shaders are unsupported.
+        pub fn dispatch_compute(
+            &self,
+            num_groups_x: GLuint,
+            num_groups_y: GLuint,
+            num_groups_z: GLuint,
+        ) {
+            forward_debug!(self.dispatch_compute(num_groups_x, num_groups_y, num_groups_z));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.DispatchCompute.is_loaded() {
+                        unsafe { gl.DispatchCompute(num_groups_x, num_groups_y, num_groups_z) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.DispatchCompute.is_loaded() {
+                        unsafe { gles.DispatchCompute(num_groups_x, num_groups_y, num_groups_z) }
+                    }
+                },
+                _ => self.record(GlCall::Other("dispatch_compute")),
+            }
+        }
+
+        /// Like `dispatch_compute`, reading the group counts from the buffer
+        /// bound to DISPATCH_INDIRECT_BUFFER at `indirect_offset`.
+        pub fn dispatch_compute_indirect(&self, indirect_offset: GLintptr) {
+            forward_debug!(self.dispatch_compute_indirect(indirect_offset));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.DispatchComputeIndirect.is_loaded() {
+                        unsafe { gl.DispatchComputeIndirect(indirect_offset) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.DispatchComputeIndirect.is_loaded() {
+                        unsafe { gles.DispatchComputeIndirect(indirect_offset) }
+                    }
+                },
+                _ => self.record(GlCall::Other("dispatch_compute_indirect")),
+            }
+        }
+
+        /// Orders incoherent writes, such as those from compute shaders,
+        /// before the accesses selected by `barriers`.
+        pub fn memory_barrier(&self, barriers: GLbitfield) {
+            forward_debug!(self.memory_barrier(barriers));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.MemoryBarrier.is_loaded() {
+                        unsafe { gl.MemoryBarrier(barriers) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.MemoryBarrier.is_loaded() {
+                        unsafe { gles.MemoryBarrier(barriers) }
+                    }
+                },
+                _ => self.record(GlCall::Other("memory_barrier")),
+            }
+        }
+
+        /// Makes texels written by earlier draws visible to texture fetches in
+        /// later ones, for sampling from a texture while rendering to it.
+        /// GL-only; a no-op elsewhere.
+        pub fn texture_barrier(&self) {
+            forward_debug!(self.texture_barrier());
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.TextureBarrier.is_loaded() {
+                        unsafe { gl.TextureBarrier() }
+                    } else if gl.TextureBarrierNV.is_loaded() {
+                        unsafe { gl.TextureBarrierNV() }
+                    }
+                },
+                Gl::Gles(..) => {},
+                _ => self.record(GlCall::Other("texture_barrier")),
+            }
+        }
+
+        /// Binds a texture level to an image unit for load/store access from
+        /// shaders. A no-op where image load/store is unsupported.
+        pub fn bind_image_texture(
+            &self,
+            unit: GLuint,
+            texture: GLuint,
+            level: GLint,
+            layered: bool,
+            layer: GLint,
+            access: GLenum,
+            format: GLenum,
+        ) {
+            forward_debug!(
+                self.bind_image_texture(unit, texture, level, layered, layer, access, format)
+            );
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.BindImageTexture.is_loaded() {
+                        unsafe {
+                            gl.BindImageTexture(
+                                unit,
+                                texture,
+                                level,
+                                layered as GLboolean,
+                                layer,
+                                access,
+                                format,
+                            )
+                        }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.BindImageTexture.is_loaded() {
+                        unsafe {
+                            gles.BindImageTexture(
+                                unit,
+                                texture,
+                                level,
+                                layered as GLboolean,
+                                layer,
+                                access,
+                                format,
+                            )
+                        }
+                    }
+                },
+                _ => self.record(GlCall::Other("bind_image_texture")),
+            }
+        }
+
+        pub fn draw_range_elements(
+            &self,
+            mode: GLenum,
+            start: GLuint,
+            end: GLuint,
+            count: GLsizei,
+            element_type: GLenum,
+            indices_offset: GLuint,
+        ) {
+            forward_debug!(self.draw_range_elements(
+                mode,
+                start,
+                end,
+                count,
+                element_type,
+                indices_offset
+            ));
+            let indices = indices_offset as *const c_void;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.DrawRangeElements(mode, start, end, count, element_type, indices)
+                },
+                Gl::Gles(gles) => unsafe {
+                    gles.DrawRangeElements(mode, start, end, count, element_type, indices)
+                },
+                _ => self.record(GlCall::Other("draw_range_elements")),
+            }
+        }
+
+        /// Requires GLES 3.2 or GL_OES_draw_elements_base_vertex on GLES; a
+        /// no-op when neither is available.
+        pub fn draw_elements_base_vertex(
+            &self,
+            mode: GLenum,
+            count: GLsizei,
+            element_type: GLenum,
+            indices_offset: GLuint,
+            base_vertex: GLint,
+        ) {
+            forward_debug!(self.draw_elements_base_vertex(
+                mode,
+                count,
+                element_type,
+                indices_offset,
+                base_vertex
+            ));
+            let indices = indices_offset as *const c_void;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.DrawElementsBaseVertex(mode, count, element_type, indices, base_vertex)
+                },
+                Gl::Gles(gles) => {
+                    if gles.DrawElementsBaseVertex.is_loaded() {
+                        unsafe {
+                            gles.DrawElementsBaseVertex(
+                                mode,
+                                count,
+                                element_type,
+                                indices,
+                                base_vertex,
+                            )
+                        }
+                    }
+                },
+                _ => self.record(GlCall::Other("draw_elements_base_vertex")),
+            }
+        }
+
+        /// Requires GLES 3.2 or GL_OES_draw_elements_base_vertex on GLES; a
+        /// no-op when neither is available.
+        pub fn draw_range_elements_base_vertex(
+            &self,
+            mode: GLenum,
+            start: GLuint,
+            end: GLuint,
+            count: GLsizei,
+            element_type: GLenum,
+            indices_offset: GLuint,
+            base_vertex: GLint,
+        ) {
+            forward_debug!(self.draw_range_elements_base_vertex(
+                mode,
+                start,
+                end,
+                count,
+                element_type,
+                indices_offset,
+                base_vertex
+            ));
+            let indices = indices_offset as *const c_void;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.DrawRangeElementsBaseVertex(
+                        mode,
+                        start,
+                        end,
+                        count,
+                        element_type,
+                        indices,
+                        base_vertex,
+                    )
+                },
+                Gl::Gles(gles) => {
+                    if gles.DrawRangeElementsBaseVertex.is_loaded() {
+                        unsafe {
+                            gles.DrawRangeElementsBaseVertex(
+                                mode,
+                                start,
+                                end,
+                                count,
+                                element_type,
+                                indices,
+                                base_vertex,
+                            )
+                        }
+                    }
+                },
+                _ => self.record(GlCall::Other("draw_range_elements_base_vertex")),
             }
         }
 
@@ -1157,7 +3714,15 @@ pub mod gl {
             indices_offset: GLuint,
             primcount: GLsizei,
         ) {
-            match self {
+            forward_debug!(self.draw_elements_instanced(
+                mode,
+                count,
+                element_type,
+                indices_offset,
+                primcount
+            ));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.DrawElementsInstanced(
                         mode,
@@ -1176,6 +3741,13 @@ pub mod gl {
                         primcount,
                     )
                 },
+                _ => self.record(GlCall::DrawElementsInstanced(
+                    mode,
+                    count,
+                    element_type,
+                    indices_offset,
+                    primcount,
+                )),
             }
         }
 
@@ -1186,7 +3758,14 @@ pub mod gl {
             renderbuffertarget: GLenum,
             renderbuffer: GLuint,
         ) {
+            forward_debug!(self.framebuffer_renderbuffer(
+                target,
+                attachment,
+                renderbuffertarget,
+                renderbuffer
+            ));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.FramebufferRenderbuffer(target, attachment, renderbuffertarget, renderbuffer)
                 },
@@ -1198,6 +3777,7 @@ pub mod gl {
                         renderbuffer,
                     )
                 },
+                _ => self.record(GlCall::Other("framebuffer_renderbuffer")),
             }
         }
 
@@ -1209,13 +3789,44 @@ pub mod gl {
             texture: GLuint,
             level: GLint,
         ) {
+            forward_debug!(
+                self.framebuffer_texture_2d(target, attachment, textarget, texture, level)
+            );
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.FramebufferTexture2D(target, attachment, textarget, texture, level)
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.FramebufferTexture2D(target, attachment, textarget, texture, level)
                 },
+                _ => self.record(GlCall::Other("framebuffer_texture_2d")),
+            }
+        }
+
+        /// Attaches slice `zoffset` of a 3D texture. GLES has no
+        /// FramebufferTexture3D, so there the slice is attached as a layer.
+        pub fn framebuffer_texture_3d(
+            &self,
+            target: GLenum,
+            attachment: GLenum,
+            textarget: GLenum,
+            texture: GLuint,
+            level: GLint,
+            zoffset: GLint,
+        ) {
+            forward_debug!(
+                self.framebuffer_texture_3d(target, attachment, textarget, texture, level, zoffset)
+            );
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.FramebufferTexture3D(target, attachment, textarget, texture, level, zoffset)
+                },
+                Gl::Gles(gles) => unsafe {
+                    gles.FramebufferTextureLayer(target, attachment, texture, level, zoffset)
+                },
+                _ => self.record(GlCall::Other("framebuffer_texture_3d")),
             }
         }
 
@@ -1227,7 +3838,11 @@ pub mod gl {
             level: GLint,
             layer: GLint,
         ) {
+            forward_debug!(
+                self.framebuffer_texture_layer(target, attachment, texture, level, layer)
+            );
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.FramebufferTextureLayer(
                         target,
@@ -1246,11 +3861,131 @@ pub mod gl {
                         layer,
                     )
                 },
+                _ => self.record(GlCall::Other("framebuffer_texture_layer")),
+            }
+        }
+
+        /// Attaches every layer of an array, cube map or 3D texture, for
+        /// layered rendering. A no-op on GLES before 3.2.
+        pub fn framebuffer_texture(
+            &self,
+            target: GLenum,
+            attachment: GLenum,
+            texture: GLuint,
+            level: GLint,
+        ) {
+            forward_debug!(self.framebuffer_texture(target, attachment, texture, level));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.FramebufferTexture(target, attachment, texture, level) },
+                Gl::Gles(gles) => {
+                    if gles.FramebufferTexture.is_loaded() {
+                        unsafe { gles.FramebufferTexture(target, attachment, texture, level) }
+                    }
+                },
+                _ => self.record(GlCall::Other("framebuffer_texture")),
+            }
+        }
+
+        /// Sets a default framebuffer parameter such as
+        /// `FRAMEBUFFER_DEFAULT_WIDTH`, used when rendering to a framebuffer
+        /// with no attachments. A no-op where unsupported.
+        pub fn framebuffer_parameter_i(&self, target: GLenum, pname: GLenum, param: GLint) {
+            forward_debug!(self.framebuffer_parameter_i(target, pname, param));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.FramebufferParameteri.is_loaded() {
+                        unsafe { gl.FramebufferParameteri(target, pname, param) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.FramebufferParameteri.is_loaded() {
+                        unsafe { gles.FramebufferParameteri(target, pname, param) }
+                    }
+                },
+                _ => self.record(GlCall::Other("framebuffer_parameter_i")),
+            }
+        }
+
+        pub fn get_framebuffer_parameter_iv(&self, target: GLenum, pname: GLenum) -> GLint {
+            forward_debug!(self.get_framebuffer_parameter_iv(target, pname));
+            let mut result = 0;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.GetFramebufferParameteriv.is_loaded() {
+                        unsafe { gl.GetFramebufferParameteriv(target, pname, &mut result) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.GetFramebufferParameteriv.is_loaded() {
+                        unsafe { gles.GetFramebufferParameteriv(target, pname, &mut result) }
+                    }
+                },
+                _ => self.record(GlCall::Other("get_framebuffer_parameter_iv")),
+            }
+            result
+        }
+
+        /// Attaches `num_views` layers of an array texture, starting at
+        /// `base_view_index`, for OVR_multiview rendering. A no-op where the
+        /// extension is unavailable.
+        pub fn framebuffer_texture_multiview_ovr(
+            &self,
+            target: GLenum,
+            attachment: GLenum,
+            texture: GLuint,
+            level: GLint,
+            base_view_index: GLint,
+            num_views: GLsizei,
+        ) {
+            forward_debug!(self.framebuffer_texture_multiview_ovr(
+                target,
+                attachment,
+                texture,
+                level,
+                base_view_index,
+                num_views
+            ));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.FramebufferTextureMultiviewOVR.is_loaded() {
+                        unsafe {
+                            gl.FramebufferTextureMultiviewOVR(
+                                target,
+                                attachment,
+                                texture,
+                                level,
+                                base_view_index,
+                                num_views,
+                            )
+                        }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.FramebufferTextureMultiviewOVR.is_loaded() {
+                        unsafe {
+                            gles.FramebufferTextureMultiviewOVR(
+                                target,
+                                attachment,
+                                texture,
+                                level,
+                                base_view_index,
+                                num_views,
+                            )
+                        }
+                    }
+                },
+                _ => self.record(GlCall::Other("framebuffer_texture_multiview_ovr")),
             }
         }
 
         pub fn invalidate_framebuffer(&self, target: GLenum, attachments: &[GLenum]) {
+            forward_debug!(self.invalidate_framebuffer(target, attachments));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.InvalidateFramebuffer(
                         target,
@@ -1265,6 +4000,7 @@ pub mod gl {
                         attachments.as_ptr(),
                     )
                 },
+                _ => self.record(GlCall::Other("invalidate_framebuffer")),
             }
         }
 
@@ -1277,7 +4013,16 @@ pub mod gl {
             width: GLsizei,
             height: GLsizei,
         ) {
-            match self {
+            forward_debug!(self.invalidate_sub_framebuffer(
+                target,
+                attachments,
+                x,
+                y,
+                width,
+                height
+            ));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.InvalidateSubFramebuffer(
                         target,
@@ -1300,23 +4045,109 @@ pub mod gl {
                         height,
                     )
                 },
+                _ => self.record(GlCall::Other("invalidate_sub_framebuffer")),
             }
         }
 
-        pub fn renderbuffer_storage(
-            &self,
-            target: GLenum,
-            internalformat: GLenum,
+        /// Tells the driver the contents of a texture level are no longer
+        /// needed. GL-only; a no-op elsewhere.
+        pub fn invalidate_tex_image(&self, texture: GLuint, level: GLint) {
+            forward_debug!(self.invalidate_tex_image(texture, level));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.InvalidateTexImage.is_loaded() {
+                        unsafe { gl.InvalidateTexImage(texture, level) }
+                    }
+                },
+                Gl::Gles(..) => {},
+                _ => self.record(GlCall::Other("invalidate_tex_image")),
+            }
+        }
+
+        pub fn invalidate_tex_sub_image(
+            &self,
+            texture: GLuint,
+            level: GLint,
+            xoffset: GLint,
+            yoffset: GLint,
+            zoffset: GLint,
+            width: GLsizei,
+            height: GLsizei,
+            depth: GLsizei,
+        ) {
+            forward_debug!(self.invalidate_tex_sub_image(
+                texture, level, xoffset, yoffset, zoffset, width, height, depth
+            ));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.InvalidateTexSubImage.is_loaded() {
+                        unsafe {
+                            gl.InvalidateTexSubImage(
+                                texture, level, xoffset, yoffset, zoffset, width, height, depth,
+                            )
+                        }
+                    }
+                },
+                Gl::Gles(..) => {},
+                _ => self.record(GlCall::Other("invalidate_tex_sub_image")),
+            }
+        }
+
+        /// Tells the driver the contents of a buffer are no longer needed, so
+        /// overwriting them needn't wait on earlier uses. GL-only; a no-op
+        /// elsewhere.
+        pub fn invalidate_buffer_data(&self, buffer: GLuint) {
+            forward_debug!(self.invalidate_buffer_data(buffer));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.InvalidateBufferData.is_loaded() {
+                        unsafe { gl.InvalidateBufferData(buffer) }
+                    }
+                },
+                Gl::Gles(..) => {},
+                _ => self.record(GlCall::Other("invalidate_buffer_data")),
+            }
+        }
+
+        pub fn invalidate_buffer_sub_data(
+            &self,
+            buffer: GLuint,
+            offset: GLintptr,
+            length: GLsizeiptr,
+        ) {
+            forward_debug!(self.invalidate_buffer_sub_data(buffer, offset, length));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.InvalidateBufferSubData.is_loaded() {
+                        unsafe { gl.InvalidateBufferSubData(buffer, offset, length) }
+                    }
+                },
+                Gl::Gles(..) => {},
+                _ => self.record(GlCall::Other("invalidate_buffer_sub_data")),
+            }
+        }
+
+        pub fn renderbuffer_storage(
+            &self,
+            target: GLenum,
+            internalformat: GLenum,
             width: GLsizei,
             height: GLsizei,
         ) {
+            forward_debug!(self.renderbuffer_storage(target, internalformat, width, height));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.RenderbufferStorage(target, internalformat, width, height)
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.RenderbufferStorage(target, internalformat, width, height)
                 },
+                _ => self.record(GlCall::Other("renderbuffer_storage")),
             }
         }
 
@@ -1328,125 +4159,830 @@ pub mod gl {
             width: GLsizei,
             height: GLsizei,
         ) {
+            forward_debug!(self.renderbuffer_storage_multisample(
+                target,
+                samples,
+                internalformat,
+                width,
+                height
+            ));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.RenderbufferStorageMultisample(target, samples, internalformat, width, height)
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.RenderbufferStorageMultisample(target, samples, internalformat, width, height)
                 },
+                _ => self.record(GlCall::Other("renderbuffer_storage_multisample")),
+            }
+        }
+
+        /// Attaches a texture that is rendered to with `samples` samples and
+        /// resolved implicitly, via GL_EXT_multisampled_render_to_texture.
+        /// GLES-only; a no-op elsewhere.
+        pub fn framebuffer_texture_2d_multisample(
+            &self,
+            target: GLenum,
+            attachment: GLenum,
+            textarget: GLenum,
+            texture: GLuint,
+            level: GLint,
+            samples: GLsizei,
+        ) {
+            forward_debug!(self.framebuffer_texture_2d_multisample(
+                target, attachment, textarget, texture, level, samples
+            ));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(..) => {},
+                Gl::Gles(gles) => {
+                    if gles.FramebufferTexture2DMultisampleEXT.is_loaded() {
+                        unsafe {
+                            gles.FramebufferTexture2DMultisampleEXT(
+                                target, attachment, textarget, texture, level, samples,
+                            )
+                        }
+                    }
+                },
+                _ => self.record(GlCall::Other("framebuffer_texture_2d_multisample")),
+            }
+        }
+
+        /// Allocates renderbuffer storage for use alongside
+        /// `framebuffer_texture_2d_multisample`. GLES-only; a no-op elsewhere.
+        pub fn renderbuffer_storage_multisample_ext(
+            &self,
+            target: GLenum,
+            samples: GLsizei,
+            internalformat: GLenum,
+            width: GLsizei,
+            height: GLsizei,
+        ) {
+            forward_debug!(self.renderbuffer_storage_multisample_ext(
+                target,
+                samples,
+                internalformat,
+                width,
+                height
+            ));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(..) => {},
+                Gl::Gles(gles) => {
+                    if gles.RenderbufferStorageMultisampleEXT.is_loaded() {
+                        unsafe {
+                            gles.RenderbufferStorageMultisampleEXT(
+                                target,
+                                samples,
+                                internalformat,
+                                width,
+                                height,
+                            )
+                        }
+                    }
+                },
+                _ => self.record(GlCall::Other("renderbuffer_storage_multisample_ext")),
             }
         }
 
         pub fn check_framebuffer_status(&self, target: GLenum) -> GLenum {
+            forward_debug!(self.check_framebuffer_status(target));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.CheckFramebufferStatus(target) },
                 Gl::Gles(gles) => unsafe { gles.CheckFramebufferStatus(target) },
+                _ => {
+                    self.record(GlCall::Other("check_framebuffer_status"));
+                    ffi::FRAMEBUFFER_COMPLETE
+                },
+            }
+        }
+
+        /// Checks the completeness of the framebuffer bound to `target`. If it
+        /// is incomplete, the error describes the status and every attachment
+        /// point, to help track down which one is at fault.
+        pub fn diagnose_framebuffer(&self, target: GLenum) -> Result<(), String> {
+            let status = self.check_framebuffer_status(target);
+            if status == ffi::FRAMEBUFFER_COMPLETE {
+                return Ok(());
+            }
+            let mut message = framebuffer_status_name(status).to_string();
+            if status == ffi::FRAMEBUFFER_UNDEFINED {
+                return Err(message);
+            }
+
+            let mut max_color_attachments = [0];
+            unsafe {
+                self.get_integer_v(ffi::MAX_COLOR_ATTACHMENTS, &mut max_color_attachments);
+            }
+            let mut attachments: Vec<(String, GLenum)> = (0..max_color_attachments[0] as GLenum)
+                .map(|i| (format!("COLOR_ATTACHMENT{}", i), ffi::COLOR_ATTACHMENT0 + i))
+                .collect();
+            attachments.push(("DEPTH_ATTACHMENT".to_string(), ffi::DEPTH_ATTACHMENT));
+            attachments.push(("STENCIL_ATTACHMENT".to_string(), ffi::STENCIL_ATTACHMENT));
+
+            for (name, attachment) in attachments {
+                let query =
+                    |pname| self.get_framebuffer_attachment_parameter_iv(target, attachment, pname);
+                let object_type = query(ffi::FRAMEBUFFER_ATTACHMENT_OBJECT_TYPE) as GLenum;
+                let kind = match object_type {
+                    ffi::NONE => continue,
+                    ffi::TEXTURE => "a texture",
+                    ffi::RENDERBUFFER => "a renderbuffer",
+                    _ => "an unknown object",
+                };
+                let object_name = query(ffi::FRAMEBUFFER_ATTACHMENT_OBJECT_NAME);
+                let bits: GLint = [
+                    ffi::FRAMEBUFFER_ATTACHMENT_RED_SIZE,
+                    ffi::FRAMEBUFFER_ATTACHMENT_GREEN_SIZE,
+                    ffi::FRAMEBUFFER_ATTACHMENT_BLUE_SIZE,
+                    ffi::FRAMEBUFFER_ATTACHMENT_ALPHA_SIZE,
+                    ffi::FRAMEBUFFER_ATTACHMENT_DEPTH_SIZE,
+                    ffi::FRAMEBUFFER_ATTACHMENT_STENCIL_SIZE,
+                ]
+                .iter()
+                .map(|&pname| query(pname))
+                .sum();
+                message.push_str(&format!("; {} is {} ({})", name, kind, object_name));
+                if bits == 0 {
+                    message.push_str(" with no storage");
+                }
             }
+            Err(message)
         }
 
         pub fn get_error(&self) -> GLenum {
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.GetError() },
                 Gl::Gles(gles) => unsafe { gles.GetError() },
+                Gl::Debug(inner, _) => inner.get_error(),
+                Gl::Mock(state) => state
+                    .errors
+                    .borrow_mut()
+                    .pop_front()
+                    .unwrap_or(ffi::NO_ERROR),
+                _ => {
+                    self.record(GlCall::Other("get_error"));
+                    ffi::NO_ERROR
+                },
+            }
+        }
+
+        pub fn get_error_typed(&self) -> GlError {
+            GlError::from_gl(self.get_error())
+        }
+
+        /// Returns every pending error flag, clearing them. Stops after
+        /// CONTEXT_LOST, which a lost context reports indefinitely.
+        pub fn drain_errors(&self) -> Vec<GlError> {
+            let mut errors = vec![];
+            loop {
+                match self.get_error_typed() {
+                    GlError::NoError => break,
+                    GlError::ContextLost => {
+                        errors.push(GlError::ContextLost);
+                        break;
+                    },
+                    error => errors.push(error),
+                }
+            }
+            errors
+        }
+
+        /// Panics if any errors are pending, listing them along with
+        /// `context` to identify the call that raised them. Only available in
+        /// debug builds.
+        #[cfg(debug_assertions)]
+        pub fn assert_no_error(&self, context: &str) {
+            let errors = self.drain_errors();
+            assert!(errors.is_empty(), "{} raised {:?}", context, errors);
+        }
+
+        /// Reports whether the context has been lost to a GPU reset, and who
+        /// caused it. Always NO_ERROR without robustness support.
+        pub fn get_graphics_reset_status(&self) -> GLenum {
+            forward_debug!(self.get_graphics_reset_status());
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) if gl.GetGraphicsResetStatus.is_loaded() => unsafe {
+                    gl.GetGraphicsResetStatus()
+                },
+                Gl::Gles(gles) if gles.GetGraphicsResetStatus.is_loaded() => unsafe {
+                    gles.GetGraphicsResetStatus()
+                },
+                _ => {
+                    self.record(GlCall::Other("get_graphics_reset_status"));
+                    ffi::NO_ERROR
+                },
+            }
+        }
+
+        /// Installs `callback` to receive KHR_debug messages, and makes debug
+        /// output synchronous so that it runs on the thread making the call
+        /// that caused the message. The callback is kept alive by this `Gl`
+        /// until it is replaced or removed, and is unregistered when the `Gl`
+        /// is dropped.
+        pub fn debug_message_callback(
+            &self,
+            callback: impl FnMut(GLenum, GLenum, GLuint, GLenum, &str) + 'static,
+        ) {
+            forward_debug!(self.debug_message_callback(callback));
+            let callback: Box<DebugCallback> = Box::new(RefCell::new(Box::new(callback)));
+            let user_param = &*callback as *const DebugCallback as *const c_void;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.DebugMessageCallback.is_loaded() {
+                        unsafe {
+                            gl.Enable(ffi::DEBUG_OUTPUT);
+                            gl.Enable(ffi::DEBUG_OUTPUT_SYNCHRONOUS);
+                            gl.DebugMessageCallback(Some(debug_message_trampoline), user_param);
+                        }
+                        // Any previous callback is only freed once the driver
+                        // has stopped referring to it.
+                        gl.state.debug_callback.set(Some(callback));
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.DebugMessageCallback.is_loaded() {
+                        unsafe {
+                            gles.Enable(ffi::DEBUG_OUTPUT);
+                            gles.Enable(ffi::DEBUG_OUTPUT_SYNCHRONOUS);
+                            gles.DebugMessageCallback(Some(debug_message_trampoline), user_param);
+                        }
+                        gles.state.debug_callback.set(Some(callback));
+                    }
+                },
+                _ => self.record(GlCall::Other("debug_message_callback")),
+            }
+        }
+
+        /// Uninstalls and frees the callback set by `debug_message_callback`.
+        pub fn remove_debug_message_callback(&self) {
+            forward_debug!(self.remove_debug_message_callback());
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.DebugMessageCallback.is_loaded() {
+                        unsafe { gl.DebugMessageCallback(None, ptr::null()) }
+                    }
+                    gl.state.debug_callback.set(None);
+                },
+                Gl::Gles(gles) => {
+                    if gles.DebugMessageCallback.is_loaded() {
+                        unsafe { gles.DebugMessageCallback(None, ptr::null()) }
+                    }
+                    gles.state.debug_callback.set(None);
+                },
+                _ => self.record(GlCall::Other("remove_debug_message_callback")),
+            }
+        }
+
+        pub fn debug_message_control(
+            &self,
+            source: GLenum,
+            type_: GLenum,
+            severity: GLenum,
+            ids: &[GLuint],
+            enabled: bool,
+        ) {
+            forward_debug!(self.debug_message_control(source, type_, severity, ids, enabled));
+            let count = ids.len() as GLsizei;
+            let enabled = enabled as GLboolean;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.DebugMessageControl.is_loaded() {
+                        unsafe {
+                            gl.DebugMessageControl(
+                                source,
+                                type_,
+                                severity,
+                                count,
+                                ids.as_ptr(),
+                                enabled,
+                            )
+                        }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.DebugMessageControl.is_loaded() {
+                        unsafe {
+                            gles.DebugMessageControl(
+                                source,
+                                type_,
+                                severity,
+                                count,
+                                ids.as_ptr(),
+                                enabled,
+                            )
+                        }
+                    }
+                },
+                _ => self.record(GlCall::Other("debug_message_control")),
+            }
+        }
+
+        pub fn debug_message_insert(
+            &self,
+            source: GLenum,
+            type_: GLenum,
+            id: GLuint,
+            severity: GLenum,
+            message: &str,
+        ) {
+            forward_debug!(self.debug_message_insert(source, type_, id, severity, message));
+            let length = message.len() as GLsizei;
+            let buf = message.as_ptr() as *const GLchar;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.DebugMessageInsert.is_loaded() {
+                        unsafe { gl.DebugMessageInsert(source, type_, id, severity, length, buf) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.DebugMessageInsert.is_loaded() {
+                        unsafe { gles.DebugMessageInsert(source, type_, id, severity, length, buf) }
+                    }
+                },
+                _ => self.record(GlCall::Other("debug_message_insert")),
+            }
+        }
+
+        pub fn object_label(&self, identifier: GLenum, name: GLuint, label: &str) {
+            forward_debug!(self.object_label(identifier, name, label));
+            let length = label.len() as GLsizei;
+            let label = label.as_ptr() as *const GLchar;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ObjectLabel.is_loaded() {
+                        unsafe { gl.ObjectLabel(identifier, name, length, label) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ObjectLabel.is_loaded() {
+                        unsafe { gles.ObjectLabel(identifier, name, length, label) }
+                    }
+                },
+                _ => self.record(GlCall::Other("object_label")),
+            }
+        }
+
+        pub fn get_object_label(&self, identifier: GLenum, name: GLuint) -> String {
+            forward_debug!(self.get_object_label(identifier, name));
+            let mut result = Vec::new();
+            let mut result_len = 0 as GLsizei;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.GetObjectLabel.is_loaded() {
+                        let max_len = self.get_integer(ffi::MAX_LABEL_LENGTH);
+                        if max_len > 0 {
+                            result.resize(max_len as usize, 0u8);
+                            unsafe {
+                                gl.GetObjectLabel(
+                                    identifier,
+                                    name,
+                                    max_len,
+                                    &mut result_len,
+                                    result.as_mut_ptr() as *mut GLchar,
+                                )
+                            }
+                        }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.GetObjectLabel.is_loaded() {
+                        let max_len = self.get_integer(ffi::MAX_LABEL_LENGTH);
+                        if max_len > 0 {
+                            result.resize(max_len as usize, 0u8);
+                            unsafe {
+                                gles.GetObjectLabel(
+                                    identifier,
+                                    name,
+                                    max_len,
+                                    &mut result_len,
+                                    result.as_mut_ptr() as *mut GLchar,
+                                )
+                            }
+                        }
+                    }
+                },
+                _ => self.record(GlCall::Other("get_object_label")),
             }
+            result.truncate(if result_len > 0 {
+                result_len as usize
+            } else {
+                0
+            });
+            String::from_utf8(result).unwrap()
         }
 
         pub fn tex_parameter_i(&self, target: GLenum, pname: GLenum, param: GLint) {
+            forward_debug!(self.tex_parameter_i(target, pname, param));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.TexParameteri(target, pname, param) },
                 Gl::Gles(gles) => unsafe { gles.TexParameteri(target, pname, param) },
+                _ => self.record(GlCall::Other("tex_parameter_i")),
             }
         }
 
+        /// Makes the depth texture bound to `target` return the result of
+        /// comparing against the reference value with `func`, as sampled by
+        /// shadow samplers, rather than the raw depth.
+        pub fn set_depth_compare(&self, target: GLenum, func: GLenum) {
+            self.tex_parameter_i(
+                target,
+                ffi::TEXTURE_COMPARE_MODE,
+                ffi::COMPARE_REF_TO_TEXTURE as GLint,
+            );
+            self.tex_parameter_i(target, ffi::TEXTURE_COMPARE_FUNC, func as GLint);
+        }
+
+        pub fn disable_depth_compare(&self, target: GLenum) {
+            self.tex_parameter_i(target, ffi::TEXTURE_COMPARE_MODE, ffi::NONE as GLint);
+        }
+
         pub fn tex_parameter_f(&self, target: GLenum, pname: GLenum, param: GLfloat) {
+            forward_debug!(self.tex_parameter_f(target, pname, param));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.TexParameterf(target, pname, param) },
                 Gl::Gles(gles) => unsafe { gles.TexParameterf(target, pname, param) },
+                _ => self.record(GlCall::Other("tex_parameter_f")),
+            }
+        }
+
+        /// Returns the largest supported anisotropic filtering level, or 1.0
+        /// (no anisotropy) without GL_EXT_texture_filter_anisotropic. The
+        /// result is looked up once per context.
+        pub fn max_texture_max_anisotropy(&self) -> GLfloat {
+            let cached = self
+                .context_state()
+                .and_then(|state| state.max_anisotropy.get());
+            if let Some(max) = cached {
+                return max;
+            }
+            let max = if self.has_extension("GL_EXT_texture_filter_anisotropic") {
+                self.get_float(ffi::MAX_TEXTURE_MAX_ANISOTROPY_EXT)
+            } else {
+                1.
+            };
+            if let Some(state) = self.context_state() {
+                state.max_anisotropy.set(Some(max));
+            }
+            max
+        }
+
+        /// Sets the anisotropic filtering level of the texture bound to
+        /// `target`, clamped to what the implementation supports. A no-op
+        /// without GL_EXT_texture_filter_anisotropic.
+        pub fn set_texture_max_anisotropy(&self, target: GLenum, value: GLfloat) {
+            let max = self.max_texture_max_anisotropy();
+            if let Some(value) = clamp_anisotropy(value, max) {
+                self.tex_parameter_f(target, ffi::TEXTURE_MAX_ANISOTROPY_EXT, value);
             }
         }
 
         pub fn get_tex_parameter_iv(&self, target: GLenum, pname: GLenum) -> GLint {
+            forward_debug!(self.get_tex_parameter_iv(target, pname));
             let mut result = 0;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.GetTexParameteriv(target, pname, &mut result) },
                 Gl::Gles(gles) => unsafe { gles.GetTexParameteriv(target, pname, &mut result) },
+                _ => self.record(GlCall::Other("get_tex_parameter_iv")),
             };
             result
         }
 
         pub fn get_tex_parameter_fv(&self, target: GLenum, pname: GLenum) -> GLfloat {
+            forward_debug!(self.get_tex_parameter_fv(target, pname));
             let mut result = 0.;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.GetTexParameterfv(target, pname, &mut result) },
                 Gl::Gles(gles) => unsafe { gles.GetTexParameterfv(target, pname, &mut result) },
+                _ => self.record(GlCall::Other("get_tex_parameter_fv")),
             };
             result
         }
 
-        pub fn get_active_attrib(&self, program: GLuint, index: GLuint) -> (i32, u32, String) {
-            let mut buf_size = [0];
-            unsafe {
-                self.get_program_iv(program, ffi::ACTIVE_ATTRIBUTE_MAX_LENGTH, &mut buf_size);
-            }
-            let mut name = vec![0u8; buf_size[0] as usize];
-            let mut length = 0 as GLsizei;
-            let mut size = 0 as i32;
-            let mut type_ = 0 as u32;
+        pub fn get_tex_level_parameter_iv(
+            &self,
+            target: GLenum,
+            level: GLint,
+            pname: GLenum,
+        ) -> GLint {
+            forward_debug!(self.get_tex_level_parameter_iv(target, level, pname));
+            let mut result = 0;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
-                    gl.GetActiveAttrib(
-                        program,
-                        index,
-                        buf_size[0],
-                        &mut length,
-                        &mut size,
-                        &mut type_,
-                        name.as_mut_ptr() as *mut GLchar,
-                    );
+                    gl.GetTexLevelParameteriv(target, level, pname, &mut result)
                 },
-                Gl::Gles(gles) => unsafe {
-                    gles.GetActiveAttrib(
-                        program,
-                        index,
-                        buf_size[0],
-                        &mut length,
-                        &mut size,
-                        &mut type_,
-                        name.as_mut_ptr() as *mut GLchar,
-                    );
+                Gl::Gles(gles) => {
+                    if gles.GetTexLevelParameteriv.is_loaded() {
+                        unsafe { gles.GetTexLevelParameteriv(target, level, pname, &mut result) }
+                    }
                 },
-            }
-            name.truncate(if length > 0 { length as usize } else { 0 });
-            (size, type_, String::from_utf8(name).unwrap())
+                _ => self.record(GlCall::Other("get_tex_level_parameter_iv")),
+            };
+            result
         }
 
-        pub fn get_active_uniform(&self, program: GLuint, index: GLuint) -> (i32, u32, String) {
-            let mut buf_size = [0];
-            unsafe {
-                self.get_program_iv(program, ffi::ACTIVE_UNIFORM_MAX_LENGTH, &mut buf_size);
-            }
-            let mut name = vec![0 as u8; buf_size[0] as usize];
-            let mut length: GLsizei = 0;
-            let mut size: i32 = 0;
-            let mut type_: u32 = 0;
-
+        pub fn get_tex_level_parameter_fv(
+            &self,
+            target: GLenum,
+            level: GLint,
+            pname: GLenum,
+        ) -> GLfloat {
+            forward_debug!(self.get_tex_level_parameter_fv(target, level, pname));
+            let mut result = 0.;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
-                    gl.GetActiveUniform(
-                        program,
-                        index,
-                        buf_size[0],
-                        &mut length,
-                        &mut size,
-                        &mut type_,
-                        name.as_mut_ptr() as *mut GLchar,
-                    );
+                    gl.GetTexLevelParameterfv(target, level, pname, &mut result)
                 },
-                Gl::Gles(gles) => unsafe {
-                    gles.GetActiveUniform(
-                        program,
-                        index,
+                Gl::Gles(gles) => {
+                    if gles.GetTexLevelParameterfv.is_loaded() {
+                        unsafe { gles.GetTexLevelParameterfv(target, level, pname, &mut result) }
+                    }
+                },
+                _ => self.record(GlCall::Other("get_tex_level_parameter_fv")),
+            };
+            result
+        }
+
+        /// The width, height and depth of `level` of the texture bound to
+        /// `target`. GLES has no 3D readback, so the depth is always 1 there.
+        fn tex_level_size(
+            &self,
+            target: GLenum,
+            level: GLint,
+        ) -> Result<(GLsizei, GLsizei, GLsizei), TexImageError> {
+            let depth = match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(..) => self
+                    .get_tex_level_parameter_iv(target, level, ffi::TEXTURE_DEPTH)
+                    .max(1),
+                Gl::Gles(gles) if !gles.GetTexLevelParameteriv.is_loaded() => {
+                    return Err(TexImageError::MissingEntryPoint("glGetTexLevelParameteriv"))
+                },
+                Gl::Debug(inner, _) => return inner.tex_level_size(target, level),
+                _ => 1,
+            };
+            let width = self.get_tex_level_parameter_iv(target, level, ffi::TEXTURE_WIDTH);
+            let height = self.get_tex_level_parameter_iv(target, level, ffi::TEXTURE_HEIGHT);
+            Ok((width, height, depth))
+        }
+
+        /// Reads back `level` of the texture bound to `target`. GLES has no
+        /// glGetTexImage, so there the level is attached to a temporary
+        /// framebuffer and read with glReadPixels instead, which needs GLES 3.1
+        /// to size the level and only works for 2D and cube map face targets
+        /// with a color-renderable format. Panics if `buffer` isn't exactly
+        /// the size of the level.
+        pub fn get_tex_image_into_buffer(
+            &self,
+            target: GLenum,
+            level: GLint,
+            format: GLenum,
+            ty: GLenum,
+            buffer: &mut [u8],
+        ) -> Result<(), TexImageError> {
+            forward_debug!(self.get_tex_image_into_buffer(target, level, format, ty, buffer));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    let (width, height, depth) = self.tex_level_size(target, level)?;
+                    let len = try_calculate_length(width, height * depth, format, ty)?;
+                    assert_eq!(len, buffer.len());
+                    self.pixel_store_i(ffi::PACK_ALIGNMENT, 1);
+                    unsafe {
+                        gl.GetTexImage(target, level, format, ty, buffer.as_mut_ptr() as *mut _)
+                    }
+                },
+                Gl::Gles(..) => {
+                    let binding = match target {
+                        ffi::TEXTURE_2D => ffi::TEXTURE_BINDING_2D,
+                        ffi::TEXTURE_CUBE_MAP_POSITIVE_X..=ffi::TEXTURE_CUBE_MAP_NEGATIVE_Z => {
+                            ffi::TEXTURE_BINDING_CUBE_MAP
+                        },
+                        _ => return Err(TexImageError::UnsupportedTarget(target)),
+                    };
+                    let (width, height, _) = self.tex_level_size(target, level)?;
+                    let len = try_calculate_length(width, height, format, ty)?;
+                    assert_eq!(len, buffer.len());
+                    let mut texture = [0];
+                    let mut read_framebuffer = [0];
+                    unsafe {
+                        self.get_integer_v(binding, &mut texture);
+                        self.get_integer_v(ffi::READ_FRAMEBUFFER_BINDING, &mut read_framebuffer);
+                    }
+                    let framebuffer = self.gen_framebuffers(1)[0];
+                    self.bind_framebuffer(ffi::READ_FRAMEBUFFER, framebuffer);
+                    self.framebuffer_texture_2d(
+                        ffi::READ_FRAMEBUFFER,
+                        ffi::COLOR_ATTACHMENT0,
+                        target,
+                        texture[0] as GLuint,
+                        level,
+                    );
+                    self.read_pixels_into_buffer(0, 0, width, height, format, ty, buffer);
+                    self.bind_framebuffer(ffi::READ_FRAMEBUFFER, read_framebuffer[0] as GLuint);
+                    self.delete_framebuffers(&[framebuffer]);
+                },
+                _ => self.record(GlCall::Other("get_tex_image_into_buffer")),
+            }
+            Ok(())
+        }
+
+        /// Bounds-checked form of `get_tex_image_into_buffer` using
+        /// GL_ARB_robustness, which writes nothing if `buffer` is too small.
+        /// Without the extension the read is emulated with the same semantics.
+        pub fn get_n_tex_image(
+            &self,
+            target: GLenum,
+            level: GLint,
+            format: GLenum,
+            ty: GLenum,
+            buffer: &mut [u8],
+        ) -> Result<(), TexImageError> {
+            forward_debug!(self.get_n_tex_image(target, level, format, ty, buffer));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) if gl.GetnTexImageARB.is_loaded() => {
+                    self.pixel_store_i(ffi::PACK_ALIGNMENT, 1);
+                    unsafe {
+                        gl.GetnTexImageARB(
+                            target,
+                            level,
+                            format,
+                            ty,
+                            buffer.len() as GLsizei,
+                            buffer.as_mut_ptr() as *mut _,
+                        )
+                    }
+                },
+                _ => {
+                    let (width, height, depth) = self.tex_level_size(target, level)?;
+                    let len = try_calculate_length(width, height * depth, format, ty)?;
+                    if len <= buffer.len() {
+                        self.get_tex_image_into_buffer(
+                            target,
+                            level,
+                            format,
+                            ty,
+                            &mut buffer[..len],
+                        )?;
+                    }
+                },
+            }
+            Ok(())
+        }
+
+        /// Returns the contents of `level` of the texture bound to `target`,
+        /// for debugging. See `get_tex_image_into_buffer`.
+        pub fn dump_texture(
+            &self,
+            target: GLenum,
+            level: GLint,
+            format: GLenum,
+            ty: GLenum,
+        ) -> Result<Vec<u8>, TexImageError> {
+            let (width, height, depth) = self.tex_level_size(target, level)?;
+            let mut pixels = vec![0; try_calculate_length(width, height * depth, format, ty)?];
+            self.get_tex_image_into_buffer(target, level, format, ty, &mut pixels)?;
+            Ok(pixels)
+        }
+
+        /// The size in bytes of `level` of the compressed texture bound to
+        /// `target`. GLES can't query this, so it always reports 0 there.
+        pub fn compressed_tex_image_size(&self, target: GLenum, level: GLint) -> GLint {
+            match self.get_type() {
+                GlType::Gl => self.get_tex_level_parameter_iv(
+                    target,
+                    level,
+                    ffi::TEXTURE_COMPRESSED_IMAGE_SIZE,
+                ),
+                GlType::Gles => 0,
+            }
+        }
+
+        /// Returns the compressed contents of `level` of the texture bound to
+        /// `target`. See `get_compressed_tex_image_into_buffer`. Always empty
+        /// on GLES, which can't read back compressed textures.
+        pub fn get_compressed_tex_image(&self, target: GLenum, level: GLint) -> Vec<u8> {
+            if self.get_type() == GlType::Gles {
+                return vec![];
+            }
+            let size = self.compressed_tex_image_size(target, level);
+            let mut data = vec![0u8; size.max(0) as usize];
+            self.get_compressed_tex_image_into_buffer(target, level, &mut data);
+            data
+        }
+
+        /// Reads the compressed contents of `level` of the texture bound to
+        /// `target` into `buffer`, which must be exactly
+        /// `compressed_tex_image_size` bytes long. Desktop GL only; GLES has
+        /// no way to read back compressed textures.
+        pub fn get_compressed_tex_image_into_buffer(
+            &self,
+            target: GLenum,
+            level: GLint,
+            buffer: &mut [u8],
+        ) {
+            forward_debug!(self.get_compressed_tex_image_into_buffer(target, level, buffer));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    assert_eq!(
+                        self.compressed_tex_image_size(target, level) as usize,
+                        buffer.len()
+                    );
+                    unsafe {
+                        gl.GetCompressedTexImage(target, level, buffer.as_mut_ptr() as *mut _)
+                    }
+                },
+                Gl::Gles(..) => panic!("compressed texture readback is not supported on GLES"),
+                _ => self.record(GlCall::Other("get_compressed_tex_image_into_buffer")),
+            }
+        }
+
+        pub fn get_active_attrib(&self, program: GLuint, index: GLuint) -> (i32, u32, String) {
+            forward_debug!(self.get_active_attrib(program, index));
+            let mut buf_size = [0];
+            unsafe {
+                self.get_program_iv(program, ffi::ACTIVE_ATTRIBUTE_MAX_LENGTH, &mut buf_size);
+            }
+            let mut name = vec![0u8; buf_size[0] as usize];
+            let mut length = 0 as GLsizei;
+            let mut size = 0 as i32;
+            let mut type_ = 0 as u32;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.GetActiveAttrib(
+                        program,
+                        index,
+                        buf_size[0],
+                        &mut length,
+                        &mut size,
+                        &mut type_,
+                        name.as_mut_ptr() as *mut GLchar,
+                    );
+                },
+                Gl::Gles(gles) => unsafe {
+                    gles.GetActiveAttrib(
+                        program,
+                        index,
+                        buf_size[0],
+                        &mut length,
+                        &mut size,
+                        &mut type_,
+                        name.as_mut_ptr() as *mut GLchar,
+                    );
+                },
+                _ => self.record(GlCall::Other("get_active_attrib")),
+            }
+            name.truncate(if length > 0 { length as usize } else { 0 });
+            (size, type_, String::from_utf8(name).unwrap())
+        }
+
+        pub fn get_active_uniform(&self, program: GLuint, index: GLuint) -> (i32, u32, String) {
+            forward_debug!(self.get_active_uniform(program, index));
+            let mut buf_size = [0];
+            unsafe {
+                self.get_program_iv(program, ffi::ACTIVE_UNIFORM_MAX_LENGTH, &mut buf_size);
+            }
+            let mut name = vec![0 as u8; buf_size[0] as usize];
+            let mut length: GLsizei = 0;
+            let mut size: i32 = 0;
+            let mut type_: u32 = 0;
+
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.GetActiveUniform(
+                        program,
+                        index,
+                        buf_size[0],
+                        &mut length,
+                        &mut size,
+                        &mut type_,
+                        name.as_mut_ptr() as *mut GLchar,
+                    );
+                },
+                Gl::Gles(gles) => unsafe {
+                    gles.GetActiveUniform(
+                        program,
+                        index,
                         buf_size[0],
                         &mut length,
                         &mut size,
@@ -1454,6 +4990,7 @@ pub mod gl {
                         name.as_mut_ptr() as *mut GLchar,
                     );
                 },
+                _ => self.record(GlCall::Other("get_active_uniform")),
             }
 
             name.truncate(if length > 0 { length as usize } else { 0 });
@@ -1462,38 +4999,128 @@ pub mod gl {
         }
 
         pub fn get_attrib_location(&self, program: GLuint, name: &str) -> c_int {
+            forward_debug!(self.get_attrib_location(program, name));
             let name = CString::new(name).unwrap();
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.GetAttribLocation(program, name.as_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.GetAttribLocation(program, name.as_ptr()) },
+                _ => {
+                    self.record(GlCall::Other("get_attrib_location"));
+                    -1
+                },
             }
         }
 
         pub fn get_frag_data_location(&self, program: GLuint, name: &str) -> c_int {
+            forward_debug!(self.get_frag_data_location(program, name));
             let name = CString::new(name).unwrap();
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.GetFragDataLocation(program, name.as_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.GetFragDataLocation(program, name.as_ptr()) },
+                _ => {
+                    self.record(GlCall::Other("get_frag_data_location"));
+                    -1
+                },
+            }
+        }
+
+        /// Desktop GL only; a no-op on GLES, where fragment outputs are bound
+        /// with layout qualifiers instead.
+        pub fn bind_frag_data_location(&self, program: GLuint, color_number: GLuint, name: &str) {
+            forward_debug!(self.bind_frag_data_location(program, color_number, name));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    let name = CString::new(name).unwrap();
+                    debug_assert!(
+                        (color_number as GLint) < self.max_draw_buffers(),
+                        "color number {} is out of range for MAX_DRAW_BUFFERS ({})",
+                        color_number,
+                        self.max_draw_buffers(),
+                    );
+                    unsafe { gl.BindFragDataLocation(program, color_number, name.as_ptr()) }
+                },
+                Gl::Gles(..) => {},
+                _ => self.record(GlCall::Other("bind_frag_data_location")),
+            }
+        }
+
+        /// Desktop GL only; a no-op on GLES.
+        pub fn bind_frag_data_location_indexed(
+            &self,
+            program: GLuint,
+            color_number: GLuint,
+            index: GLuint,
+            name: &str,
+        ) {
+            forward_debug!(self.bind_frag_data_location_indexed(
+                program,
+                color_number,
+                index,
+                name
+            ));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    let name = CString::new(name).unwrap();
+                    unsafe {
+                        gl.BindFragDataLocationIndexed(program, color_number, index, name.as_ptr())
+                    }
+                },
+                Gl::Gles(..) => {},
+                _ => self.record(GlCall::Other("bind_frag_data_location_indexed")),
+            }
+        }
+
+        /// Desktop GL only; always -1 on GLES.
+        pub fn get_frag_data_index(&self, program: GLuint, name: &str) -> c_int {
+            forward_debug!(self.get_frag_data_index(program, name));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    let name = CString::new(name).unwrap();
+                    unsafe { gl.GetFragDataIndex(program, name.as_ptr()) }
+                },
+                Gl::Gles(..) => -1,
+                _ => {
+                    self.record(GlCall::Other("get_frag_data_index"));
+                    -1
+                },
             }
         }
 
         pub fn get_uniform_location(&self, program: GLuint, name: &str) -> c_int {
+            forward_debug!(self.get_uniform_location(program, name));
             let name = CString::new(name).unwrap();
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.GetUniformLocation(program, name.as_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.GetUniformLocation(program, name.as_ptr()) },
+                _ => {
+                    self.record(GlCall::Other("get_uniform_location"));
+                    -1
+                },
             }
         }
 
         pub fn get_uniform_block_index(&self, program: GLuint, name: &str) -> GLuint {
+            forward_debug!(self.get_uniform_block_index(program, name));
             let name = CString::new(name).unwrap();
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.GetUniformBlockIndex(program, name.as_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.GetUniformBlockIndex(program, name.as_ptr()) },
+                _ => {
+                    self.record(GlCall::Other("get_uniform_block_index"));
+                    ffi::INVALID_INDEX
+                },
             }
         }
 
         pub fn get_uniform_indices(&self, program: GLuint, names: &[&str]) -> Vec<GLuint> {
+            forward_debug!(self.get_uniform_indices(program, names));
             let count = names.len() as GLsizei;
             let c_names = names
                 .iter()
@@ -1506,6 +5133,7 @@ pub mod gl {
 
             let mut indices = vec![0 as GLuint; names.len()];
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.GetUniformIndices(
                         program,
@@ -1522,6 +5150,7 @@ pub mod gl {
                         indices.as_mut_ptr(),
                     )
                 },
+                _ => self.record(GlCall::Other("get_uniform_indices")),
             }
             indices
         }
@@ -1532,8 +5161,10 @@ pub mod gl {
             uniforms: &[GLuint],
             pname: GLenum,
         ) -> Vec<GLint> {
+            forward_debug!(self.get_active_uniforms_iv(program, uniforms, pname));
             let mut results = vec![0 as GLint; uniforms.len()];
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.GetActiveUniformsiv(
                         program,
@@ -1552,6 +5183,7 @@ pub mod gl {
                         results.as_mut_ptr(),
                     )
                 },
+                _ => self.record(GlCall::Other("get_active_uniforms_iv")),
             }
             results
         }
@@ -1562,6 +5194,7 @@ pub mod gl {
             index: GLuint,
             pname: GLenum,
         ) -> Vec<GLint> {
+            forward_debug!(self.get_active_uniform_block_iv(program, index, pname));
             let buf_size = match pname {
                 ffi::UNIFORM_BLOCK_ACTIVE_UNIFORM_INDICES => {
                     self.get_active_uniform_block_iv(
@@ -1574,6 +5207,7 @@ pub mod gl {
             };
             let mut results = vec![0 as i32; buf_size];
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.GetActiveUniformBlockiv(
                         program,
@@ -1590,16 +5224,19 @@ pub mod gl {
                         results.as_mut_ptr(),
                     )
                 },
+                _ => self.record(GlCall::Other("get_active_uniform_block_iv")),
             }
             results
         }
 
         pub fn get_active_uniform_block_name(&self, program: GLuint, index: GLuint) -> String {
+            forward_debug!(self.get_active_uniform_block_name(program, index));
             let buf_size = self.get_active_uniform_block_iv(program, index, ffi::UNIFORM_BLOCK_NAME_LENGTH)[0];
             let mut name = vec![0 as u8; buf_size as usize];
             let mut length: GLsizei = 0;
 
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.GetActiveUniformBlockName(
                         program,
@@ -1618,6 +5255,7 @@ pub mod gl {
                         name.as_mut_ptr() as *mut GLchar,
                     );
                 },
+                _ => self.record(GlCall::Other("get_active_uniform_block_name")),
             }
 
             name.truncate(if length > 0 { length as usize } else { 0 });
@@ -1630,7 +5268,13 @@ pub mod gl {
             uniform_block_index: GLuint,
             uniform_block_binding: GLuint,
         ) {
+            forward_debug!(self.uniform_block_binding(
+                program,
+                uniform_block_index,
+                uniform_block_binding
+            ));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.UniformBlockBinding(
                         program,
@@ -1645,13 +5289,190 @@ pub mod gl {
                         uniform_block_binding,
                     )
                 },
+                _ => self.record(GlCall::Other("uniform_block_binding")),
+            }
+        }
+
+        /// Assigns the uniform block `name` to binding point `binding`,
+        /// returning the block's index.
+        pub fn set_uniform_block_binding_by_name(
+            &self,
+            program: GLuint,
+            name: &str,
+            binding: GLuint,
+        ) -> Result<GLuint, String> {
+            let index = self.get_uniform_block_index(program, name);
+            if index == ffi::INVALID_INDEX {
+                return Err(format!("uniform block {} is not active", name));
+            }
+            self.uniform_block_binding(program, index, binding);
+            Ok(index)
+        }
+
+        /// Binds every active uniform block of `program` to the binding point
+        /// matching its block index, returning each block's name and binding.
+        pub fn auto_bind_uniform_blocks(&self, program: GLuint) -> Vec<(String, GLuint)> {
+            let mut count = [0];
+            unsafe {
+                self.get_program_iv(program, ffi::ACTIVE_UNIFORM_BLOCKS, &mut count);
+            }
+            (0..count[0].max(0) as GLuint)
+                .map(|index| {
+                    self.uniform_block_binding(program, index, index);
+                    (self.get_active_uniform_block_name(program, index), index)
+                })
+                .collect()
+        }
+
+        pub fn get_program_interface_iv(
+            &self,
+            program: GLuint,
+            interface: GLenum,
+            pname: GLenum,
+        ) -> GLint {
+            forward_debug!(self.get_program_interface_iv(program, interface, pname));
+            let mut result = 0;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.GetProgramInterfaceiv.is_loaded() {
+                        unsafe { gl.GetProgramInterfaceiv(program, interface, pname, &mut result) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.GetProgramInterfaceiv.is_loaded() {
+                        unsafe {
+                            gles.GetProgramInterfaceiv(program, interface, pname, &mut result)
+                        }
+                    }
+                },
+                _ => self.record(GlCall::Other("get_program_interface_iv")),
+            }
+            result
+        }
+
+        pub fn get_program_resource_index(
+            &self,
+            program: GLuint,
+            interface: GLenum,
+            name: &str,
+        ) -> GLuint {
+            forward_debug!(self.get_program_resource_index(program, interface, name));
+            let name = CString::new(name).unwrap();
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) if gl.GetProgramResourceIndex.is_loaded() => unsafe {
+                    gl.GetProgramResourceIndex(program, interface, name.as_ptr())
+                },
+                Gl::Gles(gles) if gles.GetProgramResourceIndex.is_loaded() => unsafe {
+                    gles.GetProgramResourceIndex(program, interface, name.as_ptr())
+                },
+                _ => ffi::INVALID_INDEX,
+            }
+        }
+
+        pub fn get_program_resource_name(
+            &self,
+            program: GLuint,
+            interface: GLenum,
+            index: GLuint,
+        ) -> String {
+            forward_debug!(self.get_program_resource_name(program, interface, index));
+            let buf_size =
+                self.get_program_resource_iv(program, interface, index, &[ffi::NAME_LENGTH])[0];
+            let mut name = vec![0u8; buf_size as usize];
+            let mut length: GLsizei = 0;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.GetProgramResourceName.is_loaded() {
+                        unsafe {
+                            gl.GetProgramResourceName(
+                                program,
+                                interface,
+                                index,
+                                buf_size,
+                                &mut length,
+                                name.as_mut_ptr() as *mut GLchar,
+                            )
+                        }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.GetProgramResourceName.is_loaded() {
+                        unsafe {
+                            gles.GetProgramResourceName(
+                                program,
+                                interface,
+                                index,
+                                buf_size,
+                                &mut length,
+                                name.as_mut_ptr() as *mut GLchar,
+                            )
+                        }
+                    }
+                },
+                _ => self.record(GlCall::Other("get_program_resource_name")),
+            }
+            name.truncate(if length > 0 { length as usize } else { 0 });
+            String::from_utf8(name).unwrap()
+        }
+
+        pub fn get_program_resource_iv(
+            &self,
+            program: GLuint,
+            interface: GLenum,
+            index: GLuint,
+            props: &[GLenum],
+        ) -> Vec<GLint> {
+            forward_debug!(self.get_program_resource_iv(program, interface, index, props));
+            let mut results = vec![0 as GLint; props.len()];
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.GetProgramResourceiv.is_loaded() {
+                        unsafe {
+                            gl.GetProgramResourceiv(
+                                program,
+                                interface,
+                                index,
+                                props.len() as GLsizei,
+                                props.as_ptr(),
+                                results.len() as GLsizei,
+                                ptr::null_mut(),
+                                results.as_mut_ptr(),
+                            )
+                        }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.GetProgramResourceiv.is_loaded() {
+                        unsafe {
+                            gles.GetProgramResourceiv(
+                                program,
+                                interface,
+                                index,
+                                props.len() as GLsizei,
+                                props.as_ptr(),
+                                results.len() as GLsizei,
+                                ptr::null_mut(),
+                                results.as_mut_ptr(),
+                            )
+                        }
+                    }
+                },
+                _ => self.record(GlCall::Other("get_program_resource_iv")),
             }
+            results
         }
 
         pub fn bind_buffer_base(&self, program: GLenum, index: GLuint, buffer: GLuint) {
+            forward_debug!(self.bind_buffer_base(program, index, buffer));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.BindBufferBase(program, index, buffer) },
                 Gl::Gles(gles) => unsafe { gles.BindBufferBase(program, index, buffer) },
+                _ => self.record(GlCall::Other("bind_buffer_base")),
             }
         }
 
@@ -1663,15 +5484,73 @@ pub mod gl {
             offset: GLintptr,
             size: GLsizeiptr,
         ) {
+            forward_debug!(self.bind_buffer_range(program, index, buffer, offset, size));
             assert!(offset >= 0);
             assert!(size >= 0);
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.BindBufferRange(program, index, buffer, offset, size) },
-                Gl::Gles(gles) => unsafe { gles.BindBufferRange(program, index, buffer, offset, size) },
+                Gl::Gles(gles) => unsafe {
+                    gles.BindBufferRange(program, index, buffer, offset, size)
+                },
+                _ => self.record(GlCall::Other("bind_buffer_range")),
+            }
+        }
+
+        pub fn bind_buffers_base(&self, target: GLenum, first: GLuint, buffers: &[GLuint]) {
+            forward_debug!(self.bind_buffers_base(target, first, buffers));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) if gl.BindBuffersBase.is_loaded() => unsafe {
+                    gl.BindBuffersBase(target, first, buffers.len() as GLsizei, buffers.as_ptr())
+                },
+                _ => {
+                    for (i, &buffer) in buffers.iter().enumerate() {
+                        self.bind_buffer_base(target, first + i as GLuint, buffer);
+                    }
+                },
+            }
+        }
+
+        pub fn bind_buffers_range(
+            &self,
+            target: GLenum,
+            first: GLuint,
+            buffers: &[GLuint],
+            offsets: &[GLintptr],
+            sizes: &[GLsizeiptr],
+        ) {
+            forward_debug!(self.bind_buffers_range(target, first, buffers, offsets, sizes));
+            assert_eq!(buffers.len(), offsets.len());
+            assert_eq!(buffers.len(), sizes.len());
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) if gl.BindBuffersRange.is_loaded() => unsafe {
+                    gl.BindBuffersRange(
+                        target,
+                        first,
+                        buffers.len() as GLsizei,
+                        buffers.as_ptr(),
+                        offsets.as_ptr(),
+                        sizes.as_ptr(),
+                    )
+                },
+                _ => {
+                    for (i, &buffer) in buffers.iter().enumerate() {
+                        self.bind_buffer_range(
+                            target,
+                            first + i as GLuint,
+                            buffer,
+                            offsets[i],
+                            sizes[i],
+                        );
+                    }
+                },
             }
         }
 
         pub fn get_program_info_log(&self, program: GLuint) -> String {
+            forward_debug!(self.get_program_info_log(program));
             let mut max_len = [0];
             unsafe {
                 self.get_program_iv(program, ffi::INFO_LOG_LENGTH, &mut max_len);
@@ -1683,6 +5562,7 @@ pub mod gl {
             let mut result_len = 0 as GLsizei;
             let max_len = max_len[0] as GLsizei;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.GetProgramInfoLog(
                         program,
@@ -1699,6 +5579,7 @@ pub mod gl {
                         result.as_mut_ptr() as *mut GLchar,
                     )
                 },
+                _ => self.record(GlCall::Other("get_program_info_log")),
             }
             result.truncate(if result_len > 0 {
                 result_len as usize
@@ -1709,27 +5590,154 @@ pub mod gl {
         }
 
         pub unsafe fn get_program_iv(&self, program: GLuint, pname: GLenum, result: &mut [GLint]) {
+            forward_debug!(self.get_program_iv(program, pname, result));
             assert!(!result.is_empty());
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => gl.GetProgramiv(program, pname, result.as_mut_ptr()),
                 Gl::Gles(gles) => gles.GetProgramiv(program, pname, result.as_mut_ptr()),
+                _ => self.record(GlCall::Other("get_program_iv")),
             }
         }
 
-        pub unsafe fn get_vertex_attrib_fv(
-            &self,
-            index: GLuint,
-            pname: GLenum,
-            result: &mut [GLfloat],
-        ) {
-            assert!(!result.is_empty());
+        pub fn program_parameter_i(&self, program: GLuint, pname: GLenum, value: GLint) {
+            forward_debug!(self.program_parameter_i(program, pname, value));
             match self {
-                Gl::Gl(gl) => gl.GetVertexAttribfv(index, pname, result.as_mut_ptr()),
-                Gl::Gles(gles) => gles.GetVertexAttribfv(index, pname, result.as_mut_ptr()),
-            }
-        }
-
-        pub fn get_shader_info_log(&self, shader: GLuint) -> String {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramParameteri.is_loaded() {
+                        unsafe { gl.ProgramParameteri(program, pname, value) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ProgramParameteri.is_loaded() {
+                        unsafe { gles.ProgramParameteri(program, pname, value) }
+                    }
+                },
+                _ => self.record(GlCall::Other("program_parameter_i")),
+            }
+        }
+
+        /// Returns the binary format and contents of a linked program, or an
+        /// empty binary if program binaries are unsupported.
+        pub fn get_program_binary(&self, program: GLuint) -> (GLenum, Vec<u8>) {
+            forward_debug!(self.get_program_binary(program));
+            let supported = match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => gl.GetProgramBinary.is_loaded(),
+                Gl::Gles(gles) => gles.GetProgramBinary.is_loaded(),
+                _ => false,
+            };
+            if !supported {
+                return (0, Vec::new());
+            }
+            let mut len = [0];
+            unsafe {
+                self.get_program_iv(program, ffi::PROGRAM_BINARY_LENGTH, &mut len);
+            }
+            let mut binary = vec![0u8; len[0] as usize];
+            let mut result_len = 0 as GLsizei;
+            let mut format = 0 as GLenum;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.GetProgramBinary(
+                        program,
+                        len[0],
+                        &mut result_len,
+                        &mut format,
+                        binary.as_mut_ptr() as *mut c_void,
+                    )
+                },
+                Gl::Gles(gles) => unsafe {
+                    gles.GetProgramBinary(
+                        program,
+                        len[0],
+                        &mut result_len,
+                        &mut format,
+                        binary.as_mut_ptr() as *mut c_void,
+                    )
+                },
+                _ => self.record(GlCall::Other("get_program_binary")),
+            }
+            binary.truncate(if result_len > 0 {
+                result_len as usize
+            } else {
+                0
+            });
+            (format, binary)
+        }
+
+        pub fn program_binary(&self, program: GLuint, format: GLenum, binary: &[u8]) {
+            forward_debug!(self.program_binary(program, format, binary));
+            let len = binary.len() as GLsizei;
+            let binary = binary.as_ptr() as *const c_void;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramBinary.is_loaded() {
+                        unsafe { gl.ProgramBinary(program, format, binary, len) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ProgramBinary.is_loaded() {
+                        unsafe { gles.ProgramBinary(program, format, binary, len) }
+                    }
+                },
+                _ => self.record(GlCall::Other("program_binary")),
+            }
+        }
+
+        pub unsafe fn get_vertex_attrib_fv(
+            &self,
+            index: GLuint,
+            pname: GLenum,
+            result: &mut [GLfloat],
+        ) {
+            forward_debug!(self.get_vertex_attrib_fv(index, pname, result));
+            assert!(!result.is_empty());
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => gl.GetVertexAttribfv(index, pname, result.as_mut_ptr()),
+                Gl::Gles(gles) => gles.GetVertexAttribfv(index, pname, result.as_mut_ptr()),
+                _ => self.record(GlCall::Other("get_vertex_attrib_fv")),
+            }
+        }
+
+        /// # Safety
+        ///
+        /// `result` must be large enough to hold every value `pname` writes.
+        pub unsafe fn get_vertex_attrib_iv(
+            &self,
+            index: GLuint,
+            pname: GLenum,
+            result: &mut [GLint],
+        ) {
+            forward_debug!(self.get_vertex_attrib_iv(index, pname, result));
+            assert!(!result.is_empty());
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => gl.GetVertexAttribiv(index, pname, result.as_mut_ptr()),
+                Gl::Gles(gles) => gles.GetVertexAttribiv(index, pname, result.as_mut_ptr()),
+                _ => self.record(GlCall::Other("get_vertex_attrib_iv")),
+            }
+        }
+
+        pub fn get_vertex_attrib_pointer_v(&self, index: GLuint, pname: GLenum) -> isize {
+            forward_debug!(self.get_vertex_attrib_pointer_v(index, pname));
+            let mut result: *mut c_void = ptr::null_mut();
+            let result_ptr = &mut result as *mut *mut c_void;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.GetVertexAttribPointerv(index, pname, result_ptr) },
+                Gl::Gles(gles) => unsafe { gles.GetVertexAttribPointerv(index, pname, result_ptr) },
+                _ => self.record(GlCall::Other("get_vertex_attrib_pointer_v")),
+            }
+            result as isize
+        }
+
+        pub fn get_shader_info_log(&self, shader: GLuint) -> String {
+            forward_debug!(self.get_shader_info_log(shader));
             let mut max_len = [0];
             unsafe {
                 self.get_shader_iv(shader, ffi::INFO_LOG_LENGTH, &mut max_len);
@@ -1741,6 +5749,7 @@ pub mod gl {
             let mut result_len = 0 as GLsizei;
             let max_len = max_len[0] as GLsizei;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.GetShaderInfoLog(
                         shader,
@@ -1757,6 +5766,7 @@ pub mod gl {
                         result.as_mut_ptr() as *mut GLchar,
                     )
                 },
+                _ => self.record(GlCall::Other("get_shader_info_log")),
             }
             result.truncate(if result_len > 0 {
                 result_len as usize
@@ -1766,65 +5776,223 @@ pub mod gl {
             String::from_utf8(result).unwrap()
         }
 
+        /// Returns the concatenated source strings last set on `shader`.
+        pub fn get_shader_source(&self, shader: GLuint) -> String {
+            forward_debug!(self.get_shader_source(shader));
+            let mut max_len = [0];
+            unsafe {
+                self.get_shader_iv(shader, ffi::SHADER_SOURCE_LENGTH, &mut max_len);
+            }
+            if max_len[0] == 0 {
+                return String::new();
+            }
+            let mut result = vec![0u8; max_len[0] as usize];
+            let mut result_len = 0 as GLsizei;
+            let max_len = max_len[0] as GLsizei;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.GetShaderSource(
+                        shader,
+                        max_len,
+                        &mut result_len,
+                        result.as_mut_ptr() as *mut GLchar,
+                    )
+                },
+                Gl::Gles(gles) => unsafe {
+                    gles.GetShaderSource(
+                        shader,
+                        max_len,
+                        &mut result_len,
+                        result.as_mut_ptr() as *mut GLchar,
+                    )
+                },
+                _ => self.record(GlCall::Other("get_shader_source")),
+            }
+            result.truncate(if result_len > 0 {
+                result_len as usize
+            } else {
+                0
+            });
+            // shader_source accepts arbitrary bytes, so don't assume UTF-8.
+            String::from_utf8_lossy(&result).into_owned()
+        }
+
         pub unsafe fn get_integer_v(&self, name: GLenum, result: &mut [GLint]) {
+            forward_debug!(self.get_integer_v(name, result));
             assert!(!result.is_empty());
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => gl.GetIntegerv(name, result.as_mut_ptr()),
                 Gl::Gles(gles) => gles.GetIntegerv(name, result.as_mut_ptr()),
+                _ => self.record(GlCall::Other("get_integer_v")),
             }
         }
 
         pub unsafe fn get_integer64_v(&self, name: GLenum, result: &mut [GLint64]) {
+            forward_debug!(self.get_integer64_v(name, result));
             assert!(!result.is_empty());
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => gl.GetInteger64v(name, result.as_mut_ptr()),
                 Gl::Gles(gles) => gles.GetInteger64v(name, result.as_mut_ptr()),
+                _ => self.record(GlCall::Other("get_integer64_v")),
             }
         }
 
         pub unsafe fn get_integeri_v(&self, name: GLenum, index: GLuint, result: &mut [GLint]) {
+            forward_debug!(self.get_integeri_v(name, index, result));
             assert!(!result.is_empty());
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => gl.GetIntegeri_v(name, index, result.as_mut_ptr()),
                 Gl::Gles(gles) => gles.GetIntegeri_v(name, index, result.as_mut_ptr()),
+                _ => self.record(GlCall::Other("get_integeri_v")),
             }
         }
 
         pub unsafe fn get_integer64i_v(&self, name: GLenum, index: GLuint, result: &mut [GLint64]) {
+            forward_debug!(self.get_integer64i_v(name, index, result));
             assert!(!result.is_empty());
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => gl.GetInteger64i_v(name, index, result.as_mut_ptr()),
                 Gl::Gles(gles) => gles.GetInteger64i_v(name, index, result.as_mut_ptr()),
+                _ => self.record(GlCall::Other("get_integer64i_v")),
+            }
+        }
+
+        pub fn get_integer(&self, name: GLenum) -> GLint {
+            let mut result = [0];
+            unsafe {
+                self.get_integer_v(name, &mut result);
+            }
+            result[0]
+        }
+
+        pub fn get_integer_indexed(&self, name: GLenum, index: GLuint) -> GLint {
+            let mut result = [0];
+            unsafe {
+                self.get_integeri_v(name, index, &mut result);
+            }
+            result[0]
+        }
+
+        pub fn get_float(&self, name: GLenum) -> GLfloat {
+            let mut result = [0.];
+            unsafe {
+                self.get_float_v(name, &mut result);
             }
+            result[0]
+        }
+
+        pub fn get_boolean(&self, name: GLenum) -> bool {
+            let mut result = [FALSE];
+            unsafe {
+                self.get_boolean_v(name, &mut result);
+            }
+            result[0] != FALSE
         }
 
         pub unsafe fn get_boolean_v(&self, name: GLenum, result: &mut [GLboolean]) {
+            forward_debug!(self.get_boolean_v(name, result));
             assert!(!result.is_empty());
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => gl.GetBooleanv(name, result.as_mut_ptr()),
                 Gl::Gles(gles) => gles.GetBooleanv(name, result.as_mut_ptr()),
+                _ => self.record(GlCall::Other("get_boolean_v")),
             }
         }
 
         pub unsafe fn get_float_v(&self, name: GLenum, result: &mut [GLfloat]) {
+            forward_debug!(self.get_float_v(name, result));
             assert!(!result.is_empty());
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => gl.GetFloatv(name, result.as_mut_ptr()),
                 Gl::Gles(gles) => gles.GetFloatv(name, result.as_mut_ptr()),
+                _ => self.record(GlCall::Other("get_float_v")),
+            }
+        }
+
+        /// Desktop GL reports this limit in components rather than vectors;
+        /// the result here is always in vectors.
+        pub fn max_fragment_uniform_vectors(&self) -> GLint {
+            let mut result = [0];
+            unsafe {
+                match self.get_type() {
+                    GlType::Gl => {
+                        self.get_integer_v(ffi::MAX_FRAGMENT_UNIFORM_COMPONENTS, &mut result);
+                        result[0] /= 4;
+                    },
+                    GlType::Gles => {
+                        self.get_integer_v(ffi::MAX_FRAGMENT_UNIFORM_VECTORS, &mut result)
+                    },
+                }
+            }
+            result[0]
+        }
+
+        /// Desktop GL reports this limit in components rather than vectors;
+        /// the result here is always in vectors.
+        pub fn max_vertex_uniform_vectors(&self) -> GLint {
+            let mut result = [0];
+            unsafe {
+                match self.get_type() {
+                    GlType::Gl => {
+                        self.get_integer_v(ffi::MAX_VERTEX_UNIFORM_COMPONENTS, &mut result);
+                        result[0] /= 4;
+                    },
+                    GlType::Gles => {
+                        self.get_integer_v(ffi::MAX_VERTEX_UNIFORM_VECTORS, &mut result)
+                    },
+                }
+            }
+            result[0]
+        }
+
+        pub fn max_draw_buffers(&self) -> GLint {
+            let mut result = [0];
+            unsafe {
+                self.get_integer_v(ffi::MAX_DRAW_BUFFERS, &mut result);
+            }
+            result[0]
+        }
+
+        pub fn max_texture_image_units(&self) -> GLint {
+            let mut result = [0];
+            unsafe {
+                self.get_integer_v(ffi::MAX_TEXTURE_IMAGE_UNITS, &mut result);
+            }
+            result[0]
+        }
+
+        pub fn max_vertex_texture_image_units(&self) -> GLint {
+            let mut result = [0];
+            unsafe {
+                self.get_integer_v(ffi::MAX_VERTEX_TEXTURE_IMAGE_UNITS, &mut result);
             }
+            result[0]
         }
 
         pub fn compile_shader(&self, shader: GLuint) {
+            forward_debug!(self.compile_shader(shader));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.CompileShader(shader) },
                 Gl::Gles(gles) => unsafe { gles.CompileShader(shader) },
+                _ => self.record(GlCall::Other("compile_shader")),
             }
         }
 
         pub fn pixel_store_i(&self, name: GLenum, param: GLint) {
+            forward_debug!(self.pixel_store_i(name, param));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.PixelStorei(name, param) },
                 Gl::Gles(gles) => unsafe { gles.PixelStorei(name, param) },
+                _ => self.record(GlCall::PixelStoreI(name, param)),
             }
         }
 
@@ -1838,6 +6006,9 @@ pub mod gl {
             pixel_type: GLenum,
             buffer: &mut [u8],
         ) {
+            forward_debug!(
+                self.read_pixels_into_buffer(x, y, width, height, format, pixel_type, buffer)
+            );
             // Assumes that the user properly allocated the size for buffer.
             assert_eq!(
                 calculate_length(width, height, format, pixel_type),
@@ -1848,6 +6019,7 @@ pub mod gl {
             self.pixel_store_i(ffi::PACK_ALIGNMENT, 1);
 
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.ReadPixels(
                         x,
@@ -1870,15 +6042,14 @@ pub mod gl {
                         buffer.as_mut_ptr() as *mut _,
                     )
                 },
+                _ => self.record(GlCall::Other("read_pixels_into_buffer")),
             }
         }
 
-        /// Used when a WebGLBuffer object is bound to PIXEL_PACK_BUFFER.
-        /// Reads the current pixel buffer into the bound buffer object
-        /// at the provided offset. Unsafe because no validation is performed
-        /// to ensure that there is actually a buffer object bound; GL
-        /// will write at an invalid pointer value in this case.
-        pub unsafe fn read_pixels_into_pixel_pack_buffer(
+        /// Like `read_pixels_into_buffer`, but writes rows `row_length` pixels
+        /// apart, so the pixels can land in a sub-rectangle of a larger image.
+        /// A `row_length` of zero means tightly packed rows.
+        pub fn read_pixels_into_buffer_with_stride(
             &self,
             x: GLint,
             y: GLint,
@@ -1886,10 +6057,31 @@ pub mod gl {
             height: GLsizei,
             format: GLenum,
             pixel_type: GLenum,
-            buffer_byte_offset: usize,
+            row_length: GLsizei,
+            buffer: &mut [u8],
         ) {
+            forward_debug!(self.read_pixels_into_buffer_with_stride(
+                x, y, width, height, format, pixel_type, row_length, buffer
+            ));
+            assert!(row_length == 0 || row_length >= width);
+            // The last row only needs to hold `width` pixels.
+            let stride = if row_length == 0 { width } else { row_length };
+            let expected = if height > 0 {
+                calculate_length(stride, height - 1, format, pixel_type)
+                    + calculate_length(width, 1, format, pixel_type)
+            } else {
+                0
+            };
+            assert!(buffer.len() >= expected);
+
+            let previous_alignment = self.get_integer(ffi::PACK_ALIGNMENT);
+            let previous_row_length = self.get_integer(ffi::PACK_ROW_LENGTH);
+            self.pixel_store_i(ffi::PACK_ALIGNMENT, 1);
+            self.pixel_store_i(ffi::PACK_ROW_LENGTH, row_length);
+
             match self {
-                Gl::Gl(gl) =>
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
                     gl.ReadPixels(
                         x,
                         y,
@@ -1897,9 +6089,10 @@ pub mod gl {
                         height,
                         format,
                         pixel_type,
-                        buffer_byte_offset as *mut _,
-                    ),
-                Gl::Gles(gles) =>
+                        buffer.as_mut_ptr() as *mut _,
+                    )
+                },
+                Gl::Gles(gles) => unsafe {
                     gles.ReadPixels(
                         x,
                         y,
@@ -1907,8 +6100,110 @@ pub mod gl {
                         height,
                         format,
                         pixel_type,
-                        buffer_byte_offset as *mut _,
-                    ),
+                        buffer.as_mut_ptr() as *mut _,
+                    )
+                },
+                _ => self.record(GlCall::Other("read_pixels_into_buffer_with_stride")),
+            }
+
+            self.pixel_store_i(ffi::PACK_ROW_LENGTH, previous_row_length);
+            self.pixel_store_i(ffi::PACK_ALIGNMENT, previous_alignment);
+        }
+
+        /// Bounds-checked form of `read_pixels_into_buffer` using
+        /// KHR_robustness, which writes nothing if `buffer` is too small.
+        /// Without the extension the read is emulated with the same semantics.
+        pub fn read_n_pixels_into_buffer(
+            &self,
+            x: GLint,
+            y: GLint,
+            width: GLsizei,
+            height: GLsizei,
+            format: GLenum,
+            pixel_type: GLenum,
+            buffer: &mut [u8],
+        ) {
+            forward_debug!(
+                self.read_n_pixels_into_buffer(x, y, width, height, format, pixel_type, buffer)
+            );
+            let buf_size = buffer.len() as GLsizei;
+            let data = buffer.as_mut_ptr() as *mut _;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) if gl.ReadnPixels.is_loaded() => {
+                    self.pixel_store_i(ffi::PACK_ALIGNMENT, 1);
+                    unsafe {
+                        gl.ReadnPixels(x, y, width, height, format, pixel_type, buf_size, data)
+                    }
+                },
+                Gl::Gles(gles) if gles.ReadnPixels.is_loaded() => {
+                    self.pixel_store_i(ffi::PACK_ALIGNMENT, 1);
+                    unsafe {
+                        gles.ReadnPixels(x, y, width, height, format, pixel_type, buf_size, data)
+                    }
+                },
+                _ => {
+                    let len = calculate_length(width, height, format, pixel_type);
+                    if len <= buffer.len() {
+                        self.read_pixels_into_buffer(
+                            x,
+                            y,
+                            width,
+                            height,
+                            format,
+                            pixel_type,
+                            &mut buffer[..len],
+                        );
+                    }
+                },
+            }
+        }
+
+        /// Used when a WebGLBuffer object is bound to PIXEL_PACK_BUFFER.
+        /// Reads the current pixel buffer into the bound buffer object
+        /// at the provided offset. Unsafe because no validation is performed
+        /// to ensure that there is actually a buffer object bound; GL
+        /// will write at an invalid pointer value in this case.
+        pub unsafe fn read_pixels_into_pixel_pack_buffer(
+            &self,
+            x: GLint,
+            y: GLint,
+            width: GLsizei,
+            height: GLsizei,
+            format: GLenum,
+            pixel_type: GLenum,
+            buffer_byte_offset: usize,
+        ) {
+            forward_debug!(self.read_pixels_into_pixel_pack_buffer(
+                x,
+                y,
+                width,
+                height,
+                format,
+                pixel_type,
+                buffer_byte_offset
+            ));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => gl.ReadPixels(
+                    x,
+                    y,
+                    width,
+                    height,
+                    format,
+                    pixel_type,
+                    buffer_byte_offset as *mut _,
+                ),
+                Gl::Gles(gles) => gles.ReadPixels(
+                    x,
+                    y,
+                    width,
+                    height,
+                    format,
+                    pixel_type,
+                    buffer_byte_offset as *mut _,
+                ),
+                _ => self.record(GlCall::Other("read_pixels_into_pixel_pack_buffer")),
             }
         }
 
@@ -1931,705 +6226,4623 @@ pub mod gl {
             pixels
         }
 
+        /// Like `read_pixels`, but returns an error instead of panicking when
+        /// the format or type isn't supported.
+        pub fn try_read_pixels(
+            &self,
+            x: GLint,
+            y: GLint,
+            width: GLsizei,
+            height: GLsizei,
+            format: GLenum,
+            pixel_type: GLenum,
+        ) -> Result<Vec<u8>, UnsupportedPixelFormat> {
+            let len = try_calculate_length(width, height, format, pixel_type)?;
+            let mut pixels = vec![0; len];
+            self.read_pixels_into_buffer(x, y, width, height, format, pixel_type, &mut pixels);
+            Ok(pixels)
+        }
+
+        /// Reads pixels using the format and type the implementation prefers for
+        /// the bound read framebuffer, which avoids a conversion on some drivers.
+        /// Returns the format and type used alongside the pixels, or an error
+        /// if the implementation reports one that can't be sized, as it does
+        /// when the read framebuffer is incomplete.
+        pub fn read_pixels_fast(
+            &self,
+            x: GLint,
+            y: GLint,
+            width: GLsizei,
+            height: GLsizei,
+        ) -> Result<(GLenum, GLenum, Vec<u8>), UnsupportedPixelFormat> {
+            let mut format = [0];
+            let mut pixel_type = [0];
+            unsafe {
+                self.get_integer_v(ffi::IMPLEMENTATION_COLOR_READ_FORMAT, &mut format);
+                self.get_integer_v(ffi::IMPLEMENTATION_COLOR_READ_TYPE, &mut pixel_type);
+            }
+            let (format, pixel_type) = (format[0] as GLenum, pixel_type[0] as GLenum);
+            let pixels = self.try_read_pixels(x, y, width, height, format, pixel_type)?;
+            Ok((format, pixel_type, pixels))
+        }
+
         pub fn fence_sync(&self, condition: GLenum, flags: GLbitfield) -> GLsync {
+            forward_debug!(self.fence_sync(condition, flags));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.FenceSync(condition, flags) as GLsync },
                 Gl::Gles(gles) => unsafe { gles.FenceSync(condition, flags) as GLsync },
+                _ => {
+                    self.record(GlCall::Other("fence_sync"));
+                    ptr::null()
+                },
             }
         }
 
         pub fn client_wait_sync(&self, sync: GLsync, flags: GLbitfield, timeout: GLuint64) -> GLenum {
+            forward_debug!(self.client_wait_sync(sync, flags, timeout));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.ClientWaitSync(sync as *const _, flags, timeout) },
                 Gl::Gles(gles) => unsafe { gles.ClientWaitSync(sync as *const _, flags, timeout) },
+                _ => {
+                    self.record(GlCall::Other("client_wait_sync"));
+                    ffi::ALREADY_SIGNALED
+                },
             }
         }
 
         pub fn wait_sync(&self, sync: GLsync, flags: GLbitfield, timeout: GLuint64) {
+            forward_debug!(self.wait_sync(sync, flags, timeout));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.WaitSync(sync as *const _, flags, timeout) },
                 Gl::Gles(gles) => unsafe { gles.WaitSync(sync as *const _, flags, timeout) },
+                _ => self.record(GlCall::Other("wait_sync")),
             };
         }
 
         pub fn get_sync_iv(&self, sync: GLsync, pname: GLenum) -> Vec<GLint> {
+            forward_debug!(self.get_sync_iv(sync, pname));
             let mut result = vec![0 as GLint];
             match self {
-                Gl::Gl(gl) => unsafe { gl.GetSynciv(sync as *const _, pname, result.len() as _, ptr::null_mut(), result.as_mut_ptr()); },
-                Gl::Gles(gles) => unsafe { gles.GetSynciv(sync as *const _, pname, result.len() as _, ptr::null_mut(), result.as_mut_ptr()); },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.GetSynciv(
+                        sync as *const _,
+                        pname,
+                        result.len() as _,
+                        ptr::null_mut(),
+                        result.as_mut_ptr(),
+                    );
+                },
+                Gl::Gles(gles) => unsafe {
+                    gles.GetSynciv(
+                        sync as *const _,
+                        pname,
+                        result.len() as _,
+                        ptr::null_mut(),
+                        result.as_mut_ptr(),
+                    );
+                },
+                _ => self.record(GlCall::Other("get_sync_iv")),
             };
             result
         }
 
         pub fn is_sync(&self, sync: GLsync) -> bool {
+            forward_debug!(self.is_sync(sync));
             TRUE == match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.IsSync(sync as *const _) as GLboolean },
-                Gl::Gles(gles) =>  unsafe { gles.IsSync(sync as *const _) as GLboolean },
+                Gl::Gles(gles) => unsafe { gles.IsSync(sync as *const _) as GLboolean },
+                _ => {
+                    self.record(GlCall::Other("is_sync"));
+                    FALSE
+                },
             }
         }
 
         pub fn delete_sync(&self, sync: GLsync) {
+            forward_debug!(self.delete_sync(sync));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.DeleteSync(sync as *const _) },
                 Gl::Gles(gles) => unsafe { gles.DeleteSync(sync as *const _) },
+                _ => self.record(GlCall::Other("delete_sync")),
             }
         }
 
         pub fn uniform_1f(&self, location: GLint, v0: GLfloat) {
+            forward_debug!(self.uniform_1f(location, v0));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform1f(location, v0) },
                 Gl::Gles(gles) => unsafe { gles.Uniform1f(location, v0) },
+                _ => self.record(GlCall::Other("uniform_1f")),
             }
         }
 
         pub fn uniform_1fv(&self, location: GLint, values: &[f32]) {
+            forward_debug!(self.uniform_1fv(location, values));
             let len = values.len() as GLsizei;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform1fv(location, len, values.as_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.Uniform1fv(location, len, values.as_ptr()) },
+                _ => self.record(GlCall::Other("uniform_1fv")),
             }
         }
 
         pub fn uniform_1i(&self, location: GLint, v0: GLint) {
+            forward_debug!(self.uniform_1i(location, v0));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform1i(location, v0) },
                 Gl::Gles(gles) => unsafe { gles.Uniform1i(location, v0) },
+                _ => self.record(GlCall::Other("uniform_1i")),
             }
         }
 
         pub fn uniform_1iv(&self, location: GLint, values: &[i32]) {
+            forward_debug!(self.uniform_1iv(location, values));
             let len = values.len() as GLsizei;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform1iv(location, len, values.as_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.Uniform1iv(location, len, values.as_ptr()) },
+                _ => self.record(GlCall::Other("uniform_1iv")),
             }
         }
 
         pub fn uniform_1ui(&self, location: GLint, v0: GLuint) {
+            forward_debug!(self.uniform_1ui(location, v0));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform1ui(location, v0) },
                 Gl::Gles(gles) => unsafe { gles.Uniform1ui(location, v0) },
+                _ => self.record(GlCall::Other("uniform_1ui")),
             }
         }
 
         pub fn uniform_1uiv(&self, location: GLint, values: &[u32]) {
+            forward_debug!(self.uniform_1uiv(location, values));
             let len = values.len() as GLsizei;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform1uiv(location, len, values.as_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.Uniform1uiv(location, len, values.as_ptr()) },
+                _ => self.record(GlCall::Other("uniform_1uiv")),
             }
         }
 
         pub fn uniform_2f(&self, location: GLint, v0: GLfloat, v1: GLfloat) {
+            forward_debug!(self.uniform_2f(location, v0, v1));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform2f(location, v0, v1) },
                 Gl::Gles(gles) => unsafe { gles.Uniform2f(location, v0, v1) },
+                _ => self.record(GlCall::Other("uniform_2f")),
             }
         }
 
         pub fn uniform_2fv(&self, location: GLint, values: &[f32]) {
+            forward_debug!(self.uniform_2fv(location, values));
             let len = values.len() as GLsizei / 2;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform2fv(location, len, values.as_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.Uniform2fv(location, len, values.as_ptr()) },
+                _ => self.record(GlCall::Other("uniform_2fv")),
             }
         }
 
         pub fn uniform_2i(&self, location: GLint, v0: GLint, v1: GLint) {
+            forward_debug!(self.uniform_2i(location, v0, v1));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform2i(location, v0, v1) },
                 Gl::Gles(gles) => unsafe { gles.Uniform2i(location, v0, v1) },
+                _ => self.record(GlCall::Other("uniform_2i")),
             }
         }
 
         pub fn uniform_2iv(&self, location: GLint, values: &[i32]) {
+            forward_debug!(self.uniform_2iv(location, values));
             let len = values.len() as GLsizei / 2;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform2iv(location, len, values.as_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.Uniform2iv(location, len, values.as_ptr()) },
+                _ => self.record(GlCall::Other("uniform_2iv")),
             }
         }
 
         pub fn uniform_2ui(&self, location: GLint, v0: GLuint, v1: GLuint) {
+            forward_debug!(self.uniform_2ui(location, v0, v1));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform2ui(location, v0, v1) },
                 Gl::Gles(gles) => unsafe { gles.Uniform2ui(location, v0, v1) },
+                _ => self.record(GlCall::Other("uniform_2ui")),
             }
         }
 
         pub fn uniform_2uiv(&self, location: GLint, values: &[u32]) {
+            forward_debug!(self.uniform_2uiv(location, values));
             let len = values.len() as GLsizei / 2;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform2uiv(location, len, values.as_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.Uniform2uiv(location, len, values.as_ptr()) },
+                _ => self.record(GlCall::Other("uniform_2uiv")),
             }
         }
 
         pub fn uniform_3f(&self, location: GLint, v0: GLfloat, v1: GLfloat, v2: GLfloat) {
+            forward_debug!(self.uniform_3f(location, v0, v1, v2));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform3f(location, v0, v1, v2) },
                 Gl::Gles(gles) => unsafe { gles.Uniform3f(location, v0, v1, v2) },
+                _ => self.record(GlCall::Other("uniform_3f")),
             }
         }
 
         pub fn uniform_3fv(&self, location: GLint, values: &[f32]) {
+            forward_debug!(self.uniform_3fv(location, values));
             let len = values.len() as GLsizei / 3;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform3fv(location, len, values.as_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.Uniform3fv(location, len, values.as_ptr()) },
+                _ => self.record(GlCall::Other("uniform_3fv")),
             }
         }
 
         pub fn uniform_3i(&self, location: GLint, v0: GLint, v1: GLint, v2: GLint) {
+            forward_debug!(self.uniform_3i(location, v0, v1, v2));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform3i(location, v0, v1, v2) },
                 Gl::Gles(gles) => unsafe { gles.Uniform3i(location, v0, v1, v2) },
+                _ => self.record(GlCall::Other("uniform_3i")),
             }
         }
 
         pub fn uniform_3iv(&self, location: GLint, values: &[i32]) {
+            forward_debug!(self.uniform_3iv(location, values));
             let len = values.len() as GLsizei / 3;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform3iv(location, len, values.as_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.Uniform3iv(location, len, values.as_ptr()) },
+                _ => self.record(GlCall::Other("uniform_3iv")),
             }
         }
 
         pub fn uniform_3ui(&self, location: GLint, v0: GLuint, v1: GLuint, v2: GLuint) {
+            forward_debug!(self.uniform_3ui(location, v0, v1, v2));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform3ui(location, v0, v1, v2) },
                 Gl::Gles(gles) => unsafe { gles.Uniform3ui(location, v0, v1, v2) },
+                _ => self.record(GlCall::Other("uniform_3ui")),
             }
         }
 
         pub fn uniform_3uiv(&self, location: GLint, values: &[u32]) {
+            forward_debug!(self.uniform_3uiv(location, values));
             let len = values.len() as GLsizei / 3;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform3uiv(location, len, values.as_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.Uniform3uiv(location, len, values.as_ptr()) },
+                _ => self.record(GlCall::Other("uniform_3uiv")),
             }
         }
 
         pub fn uniform_4f(&self, location: GLint, x: GLfloat, y: GLfloat, z: GLfloat, w: GLfloat) {
+            forward_debug!(self.uniform_4f(location, x, y, z, w));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform4f(location, x, y, z, w) },
                 Gl::Gles(gles) => unsafe { gles.Uniform4f(location, x, y, z, w) },
+                _ => self.record(GlCall::Other("uniform_4f")),
             }
         }
 
         pub fn uniform_4i(&self, location: GLint, x: GLint, y: GLint, z: GLint, w: GLint) {
+            forward_debug!(self.uniform_4i(location, x, y, z, w));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform4i(location, x, y, z, w) },
                 Gl::Gles(gles) => unsafe { gles.Uniform4i(location, x, y, z, w) },
+                _ => self.record(GlCall::Other("uniform_4i")),
             }
         }
 
         pub fn uniform_4iv(&self, location: GLint, values: &[i32]) {
+            forward_debug!(self.uniform_4iv(location, values));
             let len = values.len() as GLsizei / 4;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform4iv(location, len, values.as_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.Uniform4iv(location, len, values.as_ptr()) },
+                _ => self.record(GlCall::Other("uniform_4iv")),
             }
         }
 
         pub fn uniform_4ui(&self, location: GLint, x: GLuint, y: GLuint, z: GLuint, w: GLuint) {
+            forward_debug!(self.uniform_4ui(location, x, y, z, w));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform4ui(location, x, y, z, w) },
                 Gl::Gles(gles) => unsafe { gles.Uniform4ui(location, x, y, z, w) },
+                _ => self.record(GlCall::Other("uniform_4ui")),
             }
         }
 
         pub fn uniform_4uiv(&self, location: GLint, values: &[u32]) {
+            forward_debug!(self.uniform_4uiv(location, values));
             let len = values.len() as GLsizei / 4;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform4uiv(location, len, values.as_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.Uniform4uiv(location, len, values.as_ptr()) },
+                _ => self.record(GlCall::Other("uniform_4uiv")),
             }
         }
 
         pub fn uniform_4fv(&self, location: GLint, values: &[f32]) {
+            forward_debug!(self.uniform_4fv(location, values));
             let len = values.len() as GLsizei / 4;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.Uniform4fv(location, len, values.as_ptr()) },
                 Gl::Gles(gles) => unsafe { gles.Uniform4fv(location, len, values.as_ptr()) },
+                _ => self.record(GlCall::Other("uniform_4fv")),
             }
         }
 
         pub fn uniform_matrix_2fv(&self, location: GLint, transpose: bool, values: &[f32]) {
+            forward_debug!(self.uniform_matrix_2fv(location, transpose, values));
             let len = values.len() as GLsizei / 4;
             let transpose = transpose as GLboolean;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.UniformMatrix2fv(location, len, transpose, values.as_ptr())
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.UniformMatrix2fv(location, len, transpose, values.as_ptr())
                 },
+                _ => self.record(GlCall::Other("uniform_matrix_2fv")),
             }
         }
 
         pub fn uniform_matrix_3fv(&self, location: GLint, transpose: bool, values: &[f32]) {
+            forward_debug!(self.uniform_matrix_3fv(location, transpose, values));
             let len = values.len() as GLsizei / 9;
             let transpose = transpose as GLboolean;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.UniformMatrix3fv(location, len, transpose, values.as_ptr())
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.UniformMatrix3fv(location, len, transpose, values.as_ptr())
                 },
+                _ => self.record(GlCall::Other("uniform_matrix_3fv")),
             }
         }
 
         pub fn uniform_matrix_4fv(&self, location: GLint, transpose: bool, values: &[f32]) {
+            forward_debug!(self.uniform_matrix_4fv(location, transpose, values));
             let len = values.len() as GLsizei / 16;
             let transpose = transpose as GLboolean;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.UniformMatrix4fv(location, len, transpose, values.as_ptr())
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.UniformMatrix4fv(location, len, transpose, values.as_ptr())
                 },
+                _ => self.record(GlCall::Other("uniform_matrix_4fv")),
             }
         }
 
         pub fn uniform_matrix_3x2fv(&self, location: GLint, transpose: bool, values: &[f32]) {
+            forward_debug!(self.uniform_matrix_3x2fv(location, transpose, values));
             let len = values.len() as GLsizei / (3 * 2);
             let transpose = transpose as GLboolean;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.UniformMatrix3x2fv(location, len, transpose, values.as_ptr())
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.UniformMatrix3x2fv(location, len, transpose, values.as_ptr())
                 },
+                _ => self.record(GlCall::Other("uniform_matrix_3x2fv")),
             }
         }
 
         pub fn uniform_matrix_4x2fv(&self, location: GLint, transpose: bool, values: &[f32]) {
+            forward_debug!(self.uniform_matrix_4x2fv(location, transpose, values));
             let len = values.len() as GLsizei / (4 * 2);
             let transpose = transpose as GLboolean;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.UniformMatrix4x2fv(location, len, transpose, values.as_ptr())
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.UniformMatrix4x2fv(location, len, transpose, values.as_ptr())
                 },
+                _ => self.record(GlCall::Other("uniform_matrix_4x2fv")),
             }
         }
 
         pub fn uniform_matrix_2x3fv(&self, location: GLint, transpose: bool, values: &[f32]) {
+            forward_debug!(self.uniform_matrix_2x3fv(location, transpose, values));
             let len = values.len() as GLsizei / (2 * 3);
             let transpose = transpose as GLboolean;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.UniformMatrix2x3fv(location, len, transpose, values.as_ptr())
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.UniformMatrix2x3fv(location, len, transpose, values.as_ptr())
                 },
+                _ => self.record(GlCall::Other("uniform_matrix_2x3fv")),
             }
         }
 
         pub fn uniform_matrix_4x3fv(&self, location: GLint, transpose: bool, values: &[f32]) {
+            forward_debug!(self.uniform_matrix_4x3fv(location, transpose, values));
             let len = values.len() as GLsizei / (4 * 3);
             let transpose = transpose as GLboolean;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.UniformMatrix4x3fv(location, len, transpose, values.as_ptr())
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.UniformMatrix4x3fv(location, len, transpose, values.as_ptr())
                 },
+                _ => self.record(GlCall::Other("uniform_matrix_4x3fv")),
             }
         }
 
         pub fn uniform_matrix_2x4fv(&self, location: GLint, transpose: bool, values: &[f32]) {
+            forward_debug!(self.uniform_matrix_2x4fv(location, transpose, values));
             let len = values.len() as GLsizei / (2 * 4);
             let transpose = transpose as GLboolean;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.UniformMatrix2x4fv(location, len, transpose, values.as_ptr())
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.UniformMatrix2x4fv(location, len, transpose, values.as_ptr())
                 },
+                _ => self.record(GlCall::Other("uniform_matrix_2x4fv")),
             }
         }
 
         pub fn uniform_matrix_3x4fv(&self, location: GLint, transpose: bool, values: &[f32]) {
+            forward_debug!(self.uniform_matrix_3x4fv(location, transpose, values));
             let len = values.len() as GLsizei / (3 * 4);
             let transpose = transpose as GLboolean;
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe {
                     gl.UniformMatrix3x4fv(location, len, transpose, values.as_ptr())
                 },
                 Gl::Gles(gles) => unsafe {
                     gles.UniformMatrix3x4fv(location, len, transpose, values.as_ptr())
                 },
+                _ => self.record(GlCall::Other("uniform_matrix_3x4fv")),
             }
         }
 
 
         pub fn use_program(&self, program: GLuint) {
+            forward_debug!(self.use_program(program));
             match self {
+                #[cfg(not(feature = "gles-only"))]
                 Gl::Gl(gl) => unsafe { gl.UseProgram(program) },
                 Gl::Gles(gles) => unsafe { gles.UseProgram(program) },
+                _ => self.record(GlCall::UseProgram(program)),
             }
         }
 
-        pub fn blit_framebuffer(
-            &self,
-            src_x0: GLint,
-            src_y0: GLint,
-            src_x1: GLint,
-            src_y1: GLint,
-            dst_x0: GLint,
-            dst_y0: GLint,
-            dst_x1: GLint,
-            dst_y1: GLint,
-            mask: GLbitfield,
-            filter: GLenum,
-        ) {
+        pub fn gen_program_pipelines(&self, n: GLsizei) -> Vec<GLuint> {
+            forward_debug!(self.gen_program_pipelines(n));
+            let loaded = match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => gl.GenProgramPipelines.is_loaded(),
+                Gl::Gles(gles) => gles.GenProgramPipelines.is_loaded(),
+                _ => true,
+            };
+            if !loaded {
+                return Vec::new();
+            }
+            let mut ids = vec![0 as GLuint; n as usize];
             match self {
-                Gl::Gl(gl) => unsafe {
-                    gl.BlitFramebuffer(
-                        src_x0, src_y0, src_x1, src_y1, dst_x0, dst_y0, dst_x1, dst_y1, mask, filter,
-                    )
-                },
-                Gl::Gles(gles) => unsafe {
-                    gles.BlitFramebuffer(
-                        src_x0, src_y0, src_x1, src_y1, dst_x0, dst_y0, dst_x1, dst_y1, mask, filter,
-                    )
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.GenProgramPipelines(n, ids.as_mut_ptr()) },
+                Gl::Gles(gles) => unsafe { gles.GenProgramPipelines(n, ids.as_mut_ptr()) },
+                _ => {
+                    self.record(GlCall::Other("gen_program_pipelines"));
+                    self.mock_ids(&mut ids);
                 },
             }
+            ids
         }
 
-        pub fn gen_queries(&self, n: GLsizei) -> Vec<GLuint> {
-            if let Gl::Gles(gles) = self {
-                if !gles.GenQueriesEXT.is_loaded() {
-                    return Vec::new();
-                }
-            }
-            let mut result = vec![0 as GLuint; n as usize];
+        pub fn delete_program_pipelines(&self, pipelines: &[GLuint]) {
+            forward_debug!(self.delete_program_pipelines(pipelines));
+            let len = pipelines.len() as GLsizei;
             match self {
-                Gl::Gl(gl) => unsafe { gl.GenQueries(n, result.as_mut_ptr()) },
-                Gl::Gles(gles) => unsafe { gles.GenQueriesEXT(n, result.as_mut_ptr()) },
-            };
-            result
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.DeleteProgramPipelines.is_loaded() {
+                        unsafe { gl.DeleteProgramPipelines(len, pipelines.as_ptr()) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.DeleteProgramPipelines.is_loaded() {
+                        unsafe { gles.DeleteProgramPipelines(len, pipelines.as_ptr()) }
+                    }
+                },
+                _ => self.record(GlCall::Other("delete_program_pipelines")),
+            }
         }
 
-        pub fn begin_query(&self, target: GLenum, id: GLuint) {
+        pub fn bind_program_pipeline(&self, pipeline: GLuint) {
+            forward_debug!(self.bind_program_pipeline(pipeline));
             match self {
-                Gl::Gl(gl) => unsafe { gl.BeginQuery(target, id) },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.BindProgramPipeline.is_loaded() {
+                        unsafe { gl.BindProgramPipeline(pipeline) }
+                    }
+                },
                 Gl::Gles(gles) => {
-                    if gles.BeginQueryEXT.is_loaded() {
-                        unsafe { gles.BeginQueryEXT(target, id) }
+                    if gles.BindProgramPipeline.is_loaded() {
+                        unsafe { gles.BindProgramPipeline(pipeline) }
                     }
                 },
+                _ => self.record(GlCall::Other("bind_program_pipeline")),
             }
         }
 
-        pub fn end_query(&self, target: GLenum) {
+        pub fn use_program_stages(&self, pipeline: GLuint, stages: GLbitfield, program: GLuint) {
+            forward_debug!(self.use_program_stages(pipeline, stages, program));
             match self {
-                Gl::Gl(gl) => unsafe { gl.EndQuery(target) },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.UseProgramStages.is_loaded() {
+                        unsafe { gl.UseProgramStages(pipeline, stages, program) }
+                    }
+                },
                 Gl::Gles(gles) => {
-                    if gles.EndQueryEXT.is_loaded() {
-                        unsafe { gles.EndQueryEXT(target) }
+                    if gles.UseProgramStages.is_loaded() {
+                        unsafe { gles.UseProgramStages(pipeline, stages, program) }
                     }
                 },
+                _ => self.record(GlCall::Other("use_program_stages")),
             }
         }
 
-        pub fn delete_queries(&self, ids: &[GLuint]) {
+        pub fn program_uniform_1f(&self, program: GLuint, location: GLint, v0: GLfloat) {
+            forward_debug!(self.program_uniform_1f(program, location, v0));
             match self {
-                Gl::Gl(gl) => unsafe { gl.DeleteQueries(ids.len() as GLsizei, ids.as_ptr()) },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform1f.is_loaded() {
+                        unsafe { gl.ProgramUniform1f(program, location, v0) }
+                    }
+                },
                 Gl::Gles(gles) => {
-                    if gles.DeleteQueriesEXT.is_loaded() {
-                        unsafe { gles.DeleteQueriesEXT(ids.len() as GLsizei, ids.as_ptr()) }
+                    if gles.ProgramUniform1f.is_loaded() {
+                        unsafe { gles.ProgramUniform1f(program, location, v0) }
                     }
                 },
+                _ => self.record(GlCall::Other("program_uniform_1f")),
             }
         }
 
-        pub fn is_query(&self, id: GLuint) -> bool {
-            TRUE == match self {
-                Gl::Gl(gl) => unsafe { gl.IsQuery(id) },
+        pub fn program_uniform_1i(&self, program: GLuint, location: GLint, v0: GLint) {
+            forward_debug!(self.program_uniform_1i(program, location, v0));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform1i.is_loaded() {
+                        unsafe { gl.ProgramUniform1i(program, location, v0) }
+                    }
+                },
                 Gl::Gles(gles) => {
-                    match gles.IsQueryEXT.is_loaded() {
-                        true => unsafe { gles.IsQueryEXT(id) },
-                        false => FALSE,
+                    if gles.ProgramUniform1i.is_loaded() {
+                        unsafe { gles.ProgramUniform1i(program, location, v0) }
                     }
                 },
+                _ => self.record(GlCall::Other("program_uniform_1i")),
             }
         }
 
-        pub fn get_query_iv(&self, target: GLenum, pname: GLenum) -> i32 {
-            let mut result = 0;
+        pub fn program_uniform_1ui(&self, program: GLuint, location: GLint, v0: GLuint) {
+            forward_debug!(self.program_uniform_1ui(program, location, v0));
             match self {
-                Gl::Gl(gl) => unsafe { gl.GetQueryiv(target, pname, &mut result) },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform1ui.is_loaded() {
+                        unsafe { gl.ProgramUniform1ui(program, location, v0) }
+                    }
+                },
                 Gl::Gles(gles) => {
-                    if gles.GetQueryivEXT.is_loaded() {
-                        unsafe { gles.GetQueryivEXT(target, pname, &mut result) }
+                    if gles.ProgramUniform1ui.is_loaded() {
+                        unsafe { gles.ProgramUniform1ui(program, location, v0) }
                     }
                 },
-            };
-            result
+                _ => self.record(GlCall::Other("program_uniform_1ui")),
+            }
         }
 
-        pub fn get_query_object_iv(&self, id: GLuint, pname: GLenum) -> i32 {
-            let mut result = 0;
+        pub fn program_uniform_1fv(&self, program: GLuint, location: GLint, values: &[f32]) {
+            forward_debug!(self.program_uniform_1fv(program, location, values));
+            let len = values.len() as GLsizei;
             match self {
-                Gl::Gl(gl) => unsafe { gl.GetQueryObjectiv(id, pname, &mut result) },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform1fv.is_loaded() {
+                        unsafe { gl.ProgramUniform1fv(program, location, len, values.as_ptr()) }
+                    }
+                },
                 Gl::Gles(gles) => {
-                    if gles.GetQueryObjectivEXT.is_loaded() {
-                        unsafe { gles.GetQueryObjectivEXT(id, pname, &mut result) }
+                    if gles.ProgramUniform1fv.is_loaded() {
+                        unsafe { gles.ProgramUniform1fv(program, location, len, values.as_ptr()) }
                     }
                 },
+                _ => self.record(GlCall::Other("program_uniform_1fv")),
             }
-            result
         }
 
-        pub fn get_query_object_uiv(&self, id: GLuint, pname: GLenum) -> u32 {
-            let mut result = 0;
+        pub fn program_uniform_1iv(&self, program: GLuint, location: GLint, values: &[i32]) {
+            forward_debug!(self.program_uniform_1iv(program, location, values));
+            let len = values.len() as GLsizei;
             match self {
-                Gl::Gl(gl) => unsafe { gl.GetQueryObjectuiv(id, pname, &mut result) },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform1iv.is_loaded() {
+                        unsafe { gl.ProgramUniform1iv(program, location, len, values.as_ptr()) }
+                    }
+                },
                 Gl::Gles(gles) => {
-                    if gles.GetQueryObjectuivEXT.is_loaded() {
-                        unsafe { gles.GetQueryObjectuivEXT(id, pname, &mut result) }
+                    if gles.ProgramUniform1iv.is_loaded() {
+                        unsafe { gles.ProgramUniform1iv(program, location, len, values.as_ptr()) }
                     }
                 },
+                _ => self.record(GlCall::Other("program_uniform_1iv")),
             }
-            result
         }
 
-        pub fn get_query_object_i64v(&self, id: GLuint, pname: GLenum) -> i64 {
-            let mut result = 0;
+        pub fn program_uniform_1uiv(&self, program: GLuint, location: GLint, values: &[u32]) {
+            forward_debug!(self.program_uniform_1uiv(program, location, values));
+            let len = values.len() as GLsizei;
             match self {
-                Gl::Gl(gl) => unsafe { gl.GetQueryObjecti64v(id, pname, &mut result) },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform1uiv.is_loaded() {
+                        unsafe { gl.ProgramUniform1uiv(program, location, len, values.as_ptr()) }
+                    }
+                },
                 Gl::Gles(gles) => {
-                    if gles.GetQueryObjecti64vEXT.is_loaded() {
-                        unsafe { gles.GetQueryObjecti64vEXT(id, pname, &mut result) }
+                    if gles.ProgramUniform1uiv.is_loaded() {
+                        unsafe { gles.ProgramUniform1uiv(program, location, len, values.as_ptr()) }
                     }
                 },
+                _ => self.record(GlCall::Other("program_uniform_1uiv")),
             }
-            result
         }
 
-        pub fn get_query_object_ui64v(&self, id: GLuint, pname: GLenum) -> u64 {
-            let mut result = 0;
+        pub fn program_uniform_2f(
+            &self,
+            program: GLuint,
+            location: GLint,
+            v0: GLfloat,
+            v1: GLfloat,
+        ) {
+            forward_debug!(self.program_uniform_2f(program, location, v0, v1));
             match self {
-                Gl::Gl(gl) => unsafe { gl.GetQueryObjectui64v(id, pname, &mut result) },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform2f.is_loaded() {
+                        unsafe { gl.ProgramUniform2f(program, location, v0, v1) }
+                    }
+                },
                 Gl::Gles(gles) => {
-                    if gles.GetQueryObjectui64vEXT.is_loaded() {
-                        unsafe { gles.GetQueryObjectui64vEXT(id, pname, &mut result) }
+                    if gles.ProgramUniform2f.is_loaded() {
+                        unsafe { gles.ProgramUniform2f(program, location, v0, v1) }
                     }
                 },
+                _ => self.record(GlCall::Other("program_uniform_2f")),
             }
-            result
         }
 
-        pub fn gen_samplers(&self, n: GLsizei) -> Vec<GLuint> {
-            let mut result = vec![0 as GLuint; n as usize];
+        pub fn program_uniform_2i(&self, program: GLuint, location: GLint, v0: GLint, v1: GLint) {
+            forward_debug!(self.program_uniform_2i(program, location, v0, v1));
             match self {
-                Gl::Gl(gl) => unsafe { gl.GenSamplers(n, result.as_mut_ptr()) },
-                Gl::Gles(gles) => unsafe { gles.GenSamplers(n, result.as_mut_ptr()) },
-            };
-            result
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform2i.is_loaded() {
+                        unsafe { gl.ProgramUniform2i(program, location, v0, v1) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ProgramUniform2i.is_loaded() {
+                        unsafe { gles.ProgramUniform2i(program, location, v0, v1) }
+                    }
+                },
+                _ => self.record(GlCall::Other("program_uniform_2i")),
+            }
         }
 
-        pub fn delete_samplers(&self, samplers: &[GLuint]) {
+        pub fn program_uniform_2ui(
+            &self,
+            program: GLuint,
+            location: GLint,
+            v0: GLuint,
+            v1: GLuint,
+        ) {
+            forward_debug!(self.program_uniform_2ui(program, location, v0, v1));
             match self {
-                Gl::Gl(gl) => unsafe { gl.DeleteSamplers(samplers.len() as GLsizei, samplers.as_ptr()) },
-                Gl::Gles(gles) => unsafe { gles.DeleteSamplers(samplers.len() as GLsizei, samplers.as_ptr()) },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform2ui.is_loaded() {
+                        unsafe { gl.ProgramUniform2ui(program, location, v0, v1) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ProgramUniform2ui.is_loaded() {
+                        unsafe { gles.ProgramUniform2ui(program, location, v0, v1) }
+                    }
+                },
+                _ => self.record(GlCall::Other("program_uniform_2ui")),
             }
         }
 
-        pub fn is_sampler(&self, sampler: GLuint) -> bool {
-            TRUE == match self {
-                Gl::Gl(gl) => unsafe { gl.IsSampler(sampler) },
-                Gl::Gles(gles) => unsafe { gles.IsSampler(sampler) },
+        pub fn program_uniform_2fv(&self, program: GLuint, location: GLint, values: &[f32]) {
+            forward_debug!(self.program_uniform_2fv(program, location, values));
+            let len = values.len() as GLsizei / 2;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform2fv.is_loaded() {
+                        unsafe { gl.ProgramUniform2fv(program, location, len, values.as_ptr()) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ProgramUniform2fv.is_loaded() {
+                        unsafe { gles.ProgramUniform2fv(program, location, len, values.as_ptr()) }
+                    }
+                },
+                _ => self.record(GlCall::Other("program_uniform_2fv")),
             }
         }
 
-        pub fn bind_sampler(&self, target: GLenum, sampler: GLuint) {
+        pub fn program_uniform_2iv(&self, program: GLuint, location: GLint, values: &[i32]) {
+            forward_debug!(self.program_uniform_2iv(program, location, values));
+            let len = values.len() as GLsizei / 2;
             match self {
-                Gl::Gl(gl) => unsafe { gl.BindSampler(target, sampler) },
-                Gl::Gles(gles) => unsafe { gles.BindSampler(target, sampler) },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform2iv.is_loaded() {
+                        unsafe { gl.ProgramUniform2iv(program, location, len, values.as_ptr()) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ProgramUniform2iv.is_loaded() {
+                        unsafe { gles.ProgramUniform2iv(program, location, len, values.as_ptr()) }
+                    }
+                },
+                _ => self.record(GlCall::Other("program_uniform_2iv")),
             }
         }
 
-        pub fn get_sampler_parameter_iv(&self, sampler: GLuint, pname: GLenum) -> Vec<GLint> {
-            let mut result = vec![0 as GLint];
+        pub fn program_uniform_2uiv(&self, program: GLuint, location: GLint, values: &[u32]) {
+            forward_debug!(self.program_uniform_2uiv(program, location, values));
+            let len = values.len() as GLsizei / 2;
             match self {
-                Gl::Gl(gl) => unsafe { gl.GetSamplerParameteriv(sampler, pname, result.as_mut_ptr()) },
-                Gl::Gles(gles) => unsafe { gles.GetSamplerParameteriv(sampler, pname, result.as_mut_ptr()) },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform2uiv.is_loaded() {
+                        unsafe { gl.ProgramUniform2uiv(program, location, len, values.as_ptr()) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ProgramUniform2uiv.is_loaded() {
+                        unsafe { gles.ProgramUniform2uiv(program, location, len, values.as_ptr()) }
+                    }
+                },
+                _ => self.record(GlCall::Other("program_uniform_2uiv")),
             }
-            result
         }
 
-        pub fn get_sampler_parameter_fv(&self, sampler: GLuint, pname: GLenum) -> Vec<GLfloat> {
-            let mut result = vec![0.0_f32 as GLfloat];
+        pub fn program_uniform_3f(
+            &self,
+            program: GLuint,
+            location: GLint,
+            v0: GLfloat,
+            v1: GLfloat,
+            v2: GLfloat,
+        ) {
+            forward_debug!(self.program_uniform_3f(program, location, v0, v1, v2));
             match self {
-                Gl::Gl(gl) => unsafe { gl.GetSamplerParameterfv(sampler, pname, result.as_mut_ptr()) },
-                Gl::Gles(gles) => unsafe { gles.GetSamplerParameterfv(sampler, pname, result.as_mut_ptr()) },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform3f.is_loaded() {
+                        unsafe { gl.ProgramUniform3f(program, location, v0, v1, v2) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ProgramUniform3f.is_loaded() {
+                        unsafe { gles.ProgramUniform3f(program, location, v0, v1, v2) }
+                    }
+                },
+                _ => self.record(GlCall::Other("program_uniform_3f")),
             }
-            result
         }
 
-        pub fn sampler_parameter_i(&self, sampler: GLuint, pname: GLenum, param: GLint) {
+        pub fn program_uniform_3i(
+            &self,
+            program: GLuint,
+            location: GLint,
+            v0: GLint,
+            v1: GLint,
+            v2: GLint,
+        ) {
+            forward_debug!(self.program_uniform_3i(program, location, v0, v1, v2));
             match self {
-                Gl::Gl(gl) => unsafe { gl.SamplerParameteri(sampler, pname, param) },
-                Gl::Gles(gles) => unsafe { gles.SamplerParameteri(sampler, pname, param) },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform3i.is_loaded() {
+                        unsafe { gl.ProgramUniform3i(program, location, v0, v1, v2) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ProgramUniform3i.is_loaded() {
+                        unsafe { gles.ProgramUniform3i(program, location, v0, v1, v2) }
+                    }
+                },
+                _ => self.record(GlCall::Other("program_uniform_3i")),
             }
         }
 
-        pub fn sampler_parameter_f(&self, sampler: GLuint, pname: GLenum, param: GLfloat) {
+        pub fn program_uniform_3ui(
+            &self,
+            program: GLuint,
+            location: GLint,
+            v0: GLuint,
+            v1: GLuint,
+            v2: GLuint,
+        ) {
+            forward_debug!(self.program_uniform_3ui(program, location, v0, v1, v2));
             match self {
-                Gl::Gl(gl) => unsafe { gl.SamplerParameterf(sampler, pname, param) },
-                Gl::Gles(gles) => unsafe { gles.SamplerParameterf(sampler, pname, param) },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform3ui.is_loaded() {
+                        unsafe { gl.ProgramUniform3ui(program, location, v0, v1, v2) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ProgramUniform3ui.is_loaded() {
+                        unsafe { gles.ProgramUniform3ui(program, location, v0, v1, v2) }
+                    }
+                },
+                _ => self.record(GlCall::Other("program_uniform_3ui")),
             }
         }
 
-        pub fn sampler_parameter_iv(&self, sampler: GLuint, pname: GLenum, params: &[GLint]) {
-            assert!(!params.is_empty());
+        pub fn program_uniform_3fv(&self, program: GLuint, location: GLint, values: &[f32]) {
+            forward_debug!(self.program_uniform_3fv(program, location, values));
+            let len = values.len() as GLsizei / 3;
             match self {
-                Gl::Gl(gl) => unsafe { gl.SamplerParameteriv(sampler, pname, params.as_ptr()) },
-                Gl::Gles(gles) => unsafe { gles.SamplerParameteriv(sampler, pname, params.as_ptr()) },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform3fv.is_loaded() {
+                        unsafe { gl.ProgramUniform3fv(program, location, len, values.as_ptr()) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ProgramUniform3fv.is_loaded() {
+                        unsafe { gles.ProgramUniform3fv(program, location, len, values.as_ptr()) }
+                    }
+                },
+                _ => self.record(GlCall::Other("program_uniform_3fv")),
             }
         }
 
-        pub fn sampler_parameter_fv(&self, sampler: GLuint, pname: GLenum, params: &[GLfloat]) {
-            assert!(!params.is_empty());
+        pub fn program_uniform_3iv(&self, program: GLuint, location: GLint, values: &[i32]) {
+            forward_debug!(self.program_uniform_3iv(program, location, values));
+            let len = values.len() as GLsizei / 3;
             match self {
-                Gl::Gl(gl) => unsafe { gl.SamplerParameterfv(sampler, pname, params.as_ptr()) },
-                Gl::Gles(gles) => unsafe { gles.SamplerParameterfv(sampler, pname, params.as_ptr()) },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform3iv.is_loaded() {
+                        unsafe { gl.ProgramUniform3iv(program, location, len, values.as_ptr()) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ProgramUniform3iv.is_loaded() {
+                        unsafe { gles.ProgramUniform3iv(program, location, len, values.as_ptr()) }
+                    }
+                },
+                _ => self.record(GlCall::Other("program_uniform_3iv")),
             }
         }
 
-        pub fn gen_transform_feedbacks(&self) -> u32 {
-            let mut ids = vec![0 as GLuint];
+        pub fn program_uniform_3uiv(&self, program: GLuint, location: GLint, values: &[u32]) {
+            forward_debug!(self.program_uniform_3uiv(program, location, values));
+            let len = values.len() as GLsizei / 3;
             match self {
-                Gl::Gl(gl) => unsafe { gl.GenTransformFeedbacks(ids.len() as _, ids.as_mut_ptr()) },
-                Gl::Gles(gles) => unsafe { gles.GenTransformFeedbacks(ids.len() as _, ids.as_mut_ptr()) },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform3uiv.is_loaded() {
+                        unsafe { gl.ProgramUniform3uiv(program, location, len, values.as_ptr()) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ProgramUniform3uiv.is_loaded() {
+                        unsafe { gles.ProgramUniform3uiv(program, location, len, values.as_ptr()) }
+                    }
+                },
+                _ => self.record(GlCall::Other("program_uniform_3uiv")),
             }
-            ids[0]
         }
 
-        pub fn delete_transform_feedbacks(&self, id: GLuint) {
-            let ids = vec![id];
+        pub fn program_uniform_4f(
+            &self,
+            program: GLuint,
+            location: GLint,
+            v0: GLfloat,
+            v1: GLfloat,
+            v2: GLfloat,
+            v3: GLfloat,
+        ) {
+            forward_debug!(self.program_uniform_4f(program, location, v0, v1, v2, v3));
             match self {
-                Gl::Gl(gl) => unsafe { gl.DeleteTransformFeedbacks(ids.len() as _, ids.as_ptr()) },
-                Gl::Gles(gles) => unsafe { gles.DeleteTransformFeedbacks(ids.len() as _, ids.as_ptr()) },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform4f.is_loaded() {
+                        unsafe { gl.ProgramUniform4f(program, location, v0, v1, v2, v3) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ProgramUniform4f.is_loaded() {
+                        unsafe { gles.ProgramUniform4f(program, location, v0, v1, v2, v3) }
+                    }
+                },
+                _ => self.record(GlCall::Other("program_uniform_4f")),
             }
         }
 
-        pub fn is_transform_feedback(&self, id: GLuint) -> bool {
-            TRUE == match self {
-                Gl::Gl(gl) => unsafe { gl.IsTransformFeedback(id) },
-                Gl::Gles(gles) => unsafe { gles.IsTransformFeedback(id) },
+        pub fn program_uniform_4i(
+            &self,
+            program: GLuint,
+            location: GLint,
+            v0: GLint,
+            v1: GLint,
+            v2: GLint,
+            v3: GLint,
+        ) {
+            forward_debug!(self.program_uniform_4i(program, location, v0, v1, v2, v3));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform4i.is_loaded() {
+                        unsafe { gl.ProgramUniform4i(program, location, v0, v1, v2, v3) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ProgramUniform4i.is_loaded() {
+                        unsafe { gles.ProgramUniform4i(program, location, v0, v1, v2, v3) }
+                    }
+                },
+                _ => self.record(GlCall::Other("program_uniform_4i")),
             }
         }
 
-        pub fn bind_transform_feedback(&self, target: GLenum, id: u32) {
+        pub fn program_uniform_4ui(
+            &self,
+            program: GLuint,
+            location: GLint,
+            v0: GLuint,
+            v1: GLuint,
+            v2: GLuint,
+            v3: GLuint,
+        ) {
+            forward_debug!(self.program_uniform_4ui(program, location, v0, v1, v2, v3));
             match self {
-                Gl::Gl(gl) => unsafe { gl.BindTransformFeedback(target, id) },
-                Gl::Gles(gles) => unsafe { gles.BindTransformFeedback(target, id) },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform4ui.is_loaded() {
+                        unsafe { gl.ProgramUniform4ui(program, location, v0, v1, v2, v3) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ProgramUniform4ui.is_loaded() {
+                        unsafe { gles.ProgramUniform4ui(program, location, v0, v1, v2, v3) }
+                    }
+                },
+                _ => self.record(GlCall::Other("program_uniform_4ui")),
             }
         }
 
-        pub fn begin_transform_feedback(&self, mode: GLenum) {
+        pub fn program_uniform_4fv(&self, program: GLuint, location: GLint, values: &[f32]) {
+            forward_debug!(self.program_uniform_4fv(program, location, values));
+            let len = values.len() as GLsizei / 4;
             match self {
-                Gl::Gl(gl) => unsafe { gl.BeginTransformFeedback(mode) },
-                Gl::Gles(gles) => unsafe { gles.BeginTransformFeedback(mode) },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform4fv.is_loaded() {
+                        unsafe { gl.ProgramUniform4fv(program, location, len, values.as_ptr()) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ProgramUniform4fv.is_loaded() {
+                        unsafe { gles.ProgramUniform4fv(program, location, len, values.as_ptr()) }
+                    }
+                },
+                _ => self.record(GlCall::Other("program_uniform_4fv")),
             }
         }
 
-        pub fn end_transform_feedback(&self) {
+        pub fn program_uniform_4iv(&self, program: GLuint, location: GLint, values: &[i32]) {
+            forward_debug!(self.program_uniform_4iv(program, location, values));
+            let len = values.len() as GLsizei / 4;
             match self {
-                Gl::Gl(gl) => unsafe { gl.EndTransformFeedback() },
-                Gl::Gles(gles) => unsafe { gles.EndTransformFeedback() },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform4iv.is_loaded() {
+                        unsafe { gl.ProgramUniform4iv(program, location, len, values.as_ptr()) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ProgramUniform4iv.is_loaded() {
+                        unsafe { gles.ProgramUniform4iv(program, location, len, values.as_ptr()) }
+                    }
+                },
+                _ => self.record(GlCall::Other("program_uniform_4iv")),
             }
         }
 
-        pub fn pause_transform_feedback(&self) {
+        pub fn program_uniform_4uiv(&self, program: GLuint, location: GLint, values: &[u32]) {
+            forward_debug!(self.program_uniform_4uiv(program, location, values));
+            let len = values.len() as GLsizei / 4;
             match self {
-                Gl::Gl(gl) => unsafe { gl.PauseTransformFeedback() },
-                Gl::Gles(gles) => unsafe { gles.PauseTransformFeedback() },
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniform4uiv.is_loaded() {
+                        unsafe { gl.ProgramUniform4uiv(program, location, len, values.as_ptr()) }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ProgramUniform4uiv.is_loaded() {
+                        unsafe { gles.ProgramUniform4uiv(program, location, len, values.as_ptr()) }
+                    }
+                },
+                _ => self.record(GlCall::Other("program_uniform_4uiv")),
+            }
+        }
+
+        pub fn program_uniform_matrix_2fv(
+            &self,
+            program: GLuint,
+            location: GLint,
+            transpose: bool,
+            values: &[f32],
+        ) {
+            forward_debug!(self.program_uniform_matrix_2fv(program, location, transpose, values));
+            let len = values.len() as GLsizei / 4;
+            let transpose = transpose as GLboolean;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniformMatrix2fv.is_loaded() {
+                        unsafe {
+                            gl.ProgramUniformMatrix2fv(
+                                program,
+                                location,
+                                len,
+                                transpose,
+                                values.as_ptr(),
+                            )
+                        }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ProgramUniformMatrix2fv.is_loaded() {
+                        unsafe {
+                            gles.ProgramUniformMatrix2fv(
+                                program,
+                                location,
+                                len,
+                                transpose,
+                                values.as_ptr(),
+                            )
+                        }
+                    }
+                },
+                _ => self.record(GlCall::Other("program_uniform_matrix_2fv")),
+            }
+        }
+
+        pub fn program_uniform_matrix_3fv(
+            &self,
+            program: GLuint,
+            location: GLint,
+            transpose: bool,
+            values: &[f32],
+        ) {
+            forward_debug!(self.program_uniform_matrix_3fv(program, location, transpose, values));
+            let len = values.len() as GLsizei / 9;
+            let transpose = transpose as GLboolean;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniformMatrix3fv.is_loaded() {
+                        unsafe {
+                            gl.ProgramUniformMatrix3fv(
+                                program,
+                                location,
+                                len,
+                                transpose,
+                                values.as_ptr(),
+                            )
+                        }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ProgramUniformMatrix3fv.is_loaded() {
+                        unsafe {
+                            gles.ProgramUniformMatrix3fv(
+                                program,
+                                location,
+                                len,
+                                transpose,
+                                values.as_ptr(),
+                            )
+                        }
+                    }
+                },
+                _ => self.record(GlCall::Other("program_uniform_matrix_3fv")),
+            }
+        }
+
+        pub fn program_uniform_matrix_4fv(
+            &self,
+            program: GLuint,
+            location: GLint,
+            transpose: bool,
+            values: &[f32],
+        ) {
+            forward_debug!(self.program_uniform_matrix_4fv(program, location, transpose, values));
+            let len = values.len() as GLsizei / 16;
+            let transpose = transpose as GLboolean;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => {
+                    if gl.ProgramUniformMatrix4fv.is_loaded() {
+                        unsafe {
+                            gl.ProgramUniformMatrix4fv(
+                                program,
+                                location,
+                                len,
+                                transpose,
+                                values.as_ptr(),
+                            )
+                        }
+                    }
+                },
+                Gl::Gles(gles) => {
+                    if gles.ProgramUniformMatrix4fv.is_loaded() {
+                        unsafe {
+                            gles.ProgramUniformMatrix4fv(
+                                program,
+                                location,
+                                len,
+                                transpose,
+                                values.as_ptr(),
+                            )
+                        }
+                    }
+                },
+                _ => self.record(GlCall::Other("program_uniform_matrix_4fv")),
+            }
+        }
+
+        pub fn blit_framebuffer(
+            &self,
+            src_x0: GLint,
+            src_y0: GLint,
+            src_x1: GLint,
+            src_y1: GLint,
+            dst_x0: GLint,
+            dst_y0: GLint,
+            dst_x1: GLint,
+            dst_y1: GLint,
+            mask: GLbitfield,
+            filter: GLenum,
+        ) {
+            forward_debug!(self.blit_framebuffer(
+                src_x0, src_y0, src_x1, src_y1, dst_x0, dst_y0, dst_x1, dst_y1, mask, filter
+            ));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.BlitFramebuffer(
+                        src_x0, src_y0, src_x1, src_y1, dst_x0, dst_y0, dst_x1, dst_y1, mask, filter,
+                    )
+                },
+                Gl::Gles(gles) => unsafe {
+                    gles.BlitFramebuffer(
+                        src_x0, src_y0, src_x1, src_y1, dst_x0, dst_y0, dst_x1, dst_y1, mask, filter,
+                    )
+                },
+                _ => self.record(GlCall::Other("blit_framebuffer")),
+            }
+        }
+
+        /// Blits the depth buffer of the bound read framebuffer to the bound
+        /// draw framebuffer. Rects are `(x0, y0, x1, y1)`; the filter is always
+        /// NEAREST since LINEAR is an error for depth blits.
+        pub fn blit_depth(
+            &self,
+            src_rect: (GLint, GLint, GLint, GLint),
+            dst_rect: (GLint, GLint, GLint, GLint),
+        ) {
+            let (src_x0, src_y0, src_x1, src_y1) = src_rect;
+            let (dst_x0, dst_y0, dst_x1, dst_y1) = dst_rect;
+            self.blit_framebuffer(
+                src_x0,
+                src_y0,
+                src_x1,
+                src_y1,
+                dst_x0,
+                dst_y0,
+                dst_x1,
+                dst_y1,
+                ffi::DEPTH_BUFFER_BIT,
+                ffi::NEAREST,
+            )
+        }
+
+        pub fn gen_queries(&self, n: GLsizei) -> Vec<GLuint> {
+            forward_debug!(self.gen_queries(n));
+            if let Gl::Gles(gles) = self {
+                if !gles.GenQueriesEXT.is_loaded() {
+                    return Vec::new();
+                }
+            }
+            let mut result = vec![0 as GLuint; n as usize];
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.GenQueries(n, result.as_mut_ptr()) },
+                Gl::Gles(gles) => unsafe { gles.GenQueriesEXT(n, result.as_mut_ptr()) },
+                _ => {
+                    self.record(GlCall::Other("gen_queries"));
+                    self.mock_ids(&mut result);
+                },
+            };
+            result
+        }
+
+        /// Whether SAMPLES_PASSED queries, which count samples rather than
+        /// just reporting whether any passed, are available. GLES only has
+        /// the boolean ANY_SAMPLES_PASSED queries.
+        pub fn supports_exact_occlusion_query(&self) -> bool {
+            self.get_type() == GlType::Gl
+        }
+
+        pub fn begin_query(&self, target: GLenum, id: GLuint) {
+            forward_debug!(self.begin_query(target, id));
+            assert!(
+                target != ffi::SAMPLES_PASSED || self.supports_exact_occlusion_query(),
+                "SAMPLES_PASSED queries are not supported on GLES; use ANY_SAMPLES_PASSED"
+            );
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.BeginQuery(target, id) },
+                Gl::Gles(gles) => {
+                    if gles.BeginQueryEXT.is_loaded() {
+                        unsafe { gles.BeginQueryEXT(target, id) }
+                    }
+                },
+                _ => self.record(GlCall::Other("begin_query")),
+            }
+        }
+
+        pub fn end_query(&self, target: GLenum) {
+            forward_debug!(self.end_query(target));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.EndQuery(target) },
+                Gl::Gles(gles) => {
+                    if gles.EndQueryEXT.is_loaded() {
+                        unsafe { gles.EndQueryEXT(target) }
+                    }
+                },
+                _ => self.record(GlCall::Other("end_query")),
+            }
+        }
+
+        pub fn query_counter(&self, id: GLuint, target: GLenum) {
+            forward_debug!(self.query_counter(id, target));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.QueryCounter(id, target) },
+                Gl::Gles(gles) => {
+                    if gles.QueryCounterEXT.is_loaded() {
+                        unsafe { gles.QueryCounterEXT(id, target) }
+                    }
+                },
+                _ => self.record(GlCall::Other("query_counter")),
+            }
+        }
+
+        pub fn delete_queries(&self, ids: &[GLuint]) {
+            forward_debug!(self.delete_queries(ids));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.DeleteQueries(ids.len() as GLsizei, ids.as_ptr()) },
+                Gl::Gles(gles) => {
+                    if gles.DeleteQueriesEXT.is_loaded() {
+                        unsafe { gles.DeleteQueriesEXT(ids.len() as GLsizei, ids.as_ptr()) }
+                    }
+                },
+                _ => self.record(GlCall::Other("delete_queries")),
+            }
+        }
+
+        pub fn is_query(&self, id: GLuint) -> bool {
+            forward_debug!(self.is_query(id));
+            TRUE == match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.IsQuery(id) },
+                Gl::Gles(gles) => match gles.IsQueryEXT.is_loaded() {
+                    true => unsafe { gles.IsQueryEXT(id) },
+                    false => FALSE,
+                },
+                _ => {
+                    self.record(GlCall::Other("is_query"));
+                    FALSE
+                },
+            }
+        }
+
+        pub fn get_query_iv(&self, target: GLenum, pname: GLenum) -> i32 {
+            forward_debug!(self.get_query_iv(target, pname));
+            let mut result = 0;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.GetQueryiv(target, pname, &mut result) },
+                Gl::Gles(gles) => {
+                    if gles.GetQueryivEXT.is_loaded() {
+                        unsafe { gles.GetQueryivEXT(target, pname, &mut result) }
+                    }
+                },
+                _ => self.record(GlCall::Other("get_query_iv")),
+            };
+            result
+        }
+
+        pub fn get_query_object_iv(&self, id: GLuint, pname: GLenum) -> i32 {
+            forward_debug!(self.get_query_object_iv(id, pname));
+            let mut result = 0;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.GetQueryObjectiv(id, pname, &mut result) },
+                Gl::Gles(gles) => {
+                    if gles.GetQueryObjectivEXT.is_loaded() {
+                        unsafe { gles.GetQueryObjectivEXT(id, pname, &mut result) }
+                    }
+                },
+                _ => self.record(GlCall::Other("get_query_object_iv")),
+            }
+            result
+        }
+
+        pub fn get_query_object_uiv(&self, id: GLuint, pname: GLenum) -> u32 {
+            forward_debug!(self.get_query_object_uiv(id, pname));
+            let mut result = 0;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.GetQueryObjectuiv(id, pname, &mut result) },
+                Gl::Gles(gles) => {
+                    if gles.GetQueryObjectuivEXT.is_loaded() {
+                        unsafe { gles.GetQueryObjectuivEXT(id, pname, &mut result) }
+                    }
+                },
+                _ => self.record(GlCall::Other("get_query_object_uiv")),
+            }
+            result
+        }
+
+        pub fn get_query_object_i64v(&self, id: GLuint, pname: GLenum) -> i64 {
+            forward_debug!(self.get_query_object_i64v(id, pname));
+            let mut result = 0;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.GetQueryObjecti64v(id, pname, &mut result) },
+                Gl::Gles(gles) => {
+                    if gles.GetQueryObjecti64vEXT.is_loaded() {
+                        unsafe { gles.GetQueryObjecti64vEXT(id, pname, &mut result) }
+                    }
+                },
+                _ => self.record(GlCall::Other("get_query_object_i64v")),
+            }
+            result
+        }
+
+        pub fn get_query_object_ui64v(&self, id: GLuint, pname: GLenum) -> u64 {
+            forward_debug!(self.get_query_object_ui64v(id, pname));
+            let mut result = 0;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.GetQueryObjectui64v(id, pname, &mut result) },
+                Gl::Gles(gles) => {
+                    if gles.GetQueryObjectui64vEXT.is_loaded() {
+                        unsafe { gles.GetQueryObjectui64vEXT(id, pname, &mut result) }
+                    }
+                },
+                _ => self.record(GlCall::Other("get_query_object_ui64v")),
+            }
+            result
+        }
+
+        /// Waits for and returns the result of query `id`, such as a
+        /// TIMESTAMP in nanoseconds.
+        pub fn get_query_result_u64(&self, id: GLuint) -> u64 {
+            self.get_query_object_ui64v(id, ffi::QUERY_RESULT)
+        }
+
+        pub fn gen_samplers(&self, n: GLsizei) -> Vec<GLuint> {
+            forward_debug!(self.gen_samplers(n));
+            let mut result = vec![0 as GLuint; n as usize];
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.GenSamplers(n, result.as_mut_ptr()) },
+                Gl::Gles(gles) => unsafe { gles.GenSamplers(n, result.as_mut_ptr()) },
+                _ => {
+                    self.record(GlCall::Other("gen_samplers"));
+                    self.mock_ids(&mut result);
+                },
+            };
+            result
+        }
+
+        pub fn delete_samplers(&self, samplers: &[GLuint]) {
+            forward_debug!(self.delete_samplers(samplers));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.DeleteSamplers(samplers.len() as GLsizei, samplers.as_ptr())
+                },
+                Gl::Gles(gles) => unsafe {
+                    gles.DeleteSamplers(samplers.len() as GLsizei, samplers.as_ptr())
+                },
+                _ => self.record(GlCall::Other("delete_samplers")),
             }
         }
 
-        pub fn resume_transform_feedback(&self) {
-            match self {
-                Gl::Gl(gl) => unsafe { gl.ResumeTransformFeedback() },
-                Gl::Gles(gles) => unsafe { gles.ResumeTransformFeedback() },
-            }
+        pub fn is_sampler(&self, sampler: GLuint) -> bool {
+            forward_debug!(self.is_sampler(sampler));
+            TRUE == match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.IsSampler(sampler) },
+                Gl::Gles(gles) => unsafe { gles.IsSampler(sampler) },
+                _ => {
+                    self.record(GlCall::Other("is_sampler"));
+                    FALSE
+                },
+            }
+        }
+
+        pub fn bind_sampler(&self, target: GLenum, sampler: GLuint) {
+            forward_debug!(self.bind_sampler(target, sampler));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.BindSampler(target, sampler) },
+                Gl::Gles(gles) => unsafe { gles.BindSampler(target, sampler) },
+                _ => self.record(GlCall::Other("bind_sampler")),
+            }
+        }
+
+        pub fn get_sampler_parameter_iv(&self, sampler: GLuint, pname: GLenum) -> Vec<GLint> {
+            forward_debug!(self.get_sampler_parameter_iv(sampler, pname));
+            let mut result = vec![0 as GLint];
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.GetSamplerParameteriv(sampler, pname, result.as_mut_ptr())
+                },
+                Gl::Gles(gles) => unsafe {
+                    gles.GetSamplerParameteriv(sampler, pname, result.as_mut_ptr())
+                },
+                _ => self.record(GlCall::Other("get_sampler_parameter_iv")),
+            }
+            result
+        }
+
+        pub fn get_sampler_parameter_fv(&self, sampler: GLuint, pname: GLenum) -> Vec<GLfloat> {
+            forward_debug!(self.get_sampler_parameter_fv(sampler, pname));
+            let mut result = vec![0.0_f32 as GLfloat];
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.GetSamplerParameterfv(sampler, pname, result.as_mut_ptr())
+                },
+                Gl::Gles(gles) => unsafe {
+                    gles.GetSamplerParameterfv(sampler, pname, result.as_mut_ptr())
+                },
+                _ => self.record(GlCall::Other("get_sampler_parameter_fv")),
+            }
+            result
+        }
+
+        pub fn sampler_parameter_i(&self, sampler: GLuint, pname: GLenum, param: GLint) {
+            forward_debug!(self.sampler_parameter_i(sampler, pname, param));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.SamplerParameteri(sampler, pname, param) },
+                Gl::Gles(gles) => unsafe { gles.SamplerParameteri(sampler, pname, param) },
+                _ => self.record(GlCall::Other("sampler_parameter_i")),
+            }
+        }
+
+        pub fn sampler_parameter_f(&self, sampler: GLuint, pname: GLenum, param: GLfloat) {
+            forward_debug!(self.sampler_parameter_f(sampler, pname, param));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.SamplerParameterf(sampler, pname, param) },
+                Gl::Gles(gles) => unsafe { gles.SamplerParameterf(sampler, pname, param) },
+                _ => self.record(GlCall::Other("sampler_parameter_f")),
+            }
+        }
+
+        pub fn sampler_parameter_iv(&self, sampler: GLuint, pname: GLenum, params: &[GLint]) {
+            forward_debug!(self.sampler_parameter_iv(sampler, pname, params));
+            assert!(!params.is_empty());
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.SamplerParameteriv(sampler, pname, params.as_ptr()) },
+                Gl::Gles(gles) => unsafe {
+                    gles.SamplerParameteriv(sampler, pname, params.as_ptr())
+                },
+                _ => self.record(GlCall::Other("sampler_parameter_iv")),
+            }
+        }
+
+        pub fn sampler_parameter_fv(&self, sampler: GLuint, pname: GLenum, params: &[GLfloat]) {
+            forward_debug!(self.sampler_parameter_fv(sampler, pname, params));
+            assert!(!params.is_empty());
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.SamplerParameterfv(sampler, pname, params.as_ptr()) },
+                Gl::Gles(gles) => unsafe {
+                    gles.SamplerParameterfv(sampler, pname, params.as_ptr())
+                },
+                _ => self.record(GlCall::Other("sampler_parameter_fv")),
+            }
+        }
+
+        /// Sets a sampler's floating-point border color. GLES needs 3.2 or
+        /// GL_EXT_texture_border_clamp, detected by whether
+        /// glSamplerParameterIiv loaded; without either this is a no-op.
+        pub fn sampler_border_color_f(&self, sampler: GLuint, color: [GLfloat; 4]) {
+            forward_debug!(self.sampler_border_color_f(sampler, color));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.SamplerParameterfv(sampler, ffi::TEXTURE_BORDER_COLOR, color.as_ptr())
+                },
+                Gl::Gles(gles) => {
+                    if gles.SamplerParameterIiv.is_loaded() {
+                        unsafe {
+                            gles.SamplerParameterfv(
+                                sampler,
+                                ffi::TEXTURE_BORDER_COLOR,
+                                color.as_ptr(),
+                            )
+                        }
+                    }
+                },
+                _ => self.record(GlCall::Other("sampler_border_color_f")),
+            }
+        }
+
+        /// Sets a sampler's border color for integer textures, which must go
+        /// through glSamplerParameterIiv rather than glSamplerParameteriv.
+        pub fn sampler_border_color_i(&self, sampler: GLuint, color: [GLint; 4]) {
+            forward_debug!(self.sampler_border_color_i(sampler, color));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.SamplerParameterIiv(sampler, ffi::TEXTURE_BORDER_COLOR, color.as_ptr())
+                },
+                Gl::Gles(gles) => {
+                    if gles.SamplerParameterIiv.is_loaded() {
+                        unsafe {
+                            gles.SamplerParameterIiv(
+                                sampler,
+                                ffi::TEXTURE_BORDER_COLOR,
+                                color.as_ptr(),
+                            )
+                        }
+                    }
+                },
+                _ => self.record(GlCall::Other("sampler_border_color_i")),
+            }
+        }
+
+        pub fn gen_transform_feedbacks(&self) -> u32 {
+            forward_debug!(self.gen_transform_feedbacks());
+            let mut ids = vec![0 as GLuint];
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.GenTransformFeedbacks(ids.len() as _, ids.as_mut_ptr()) },
+                Gl::Gles(gles) => unsafe {
+                    gles.GenTransformFeedbacks(ids.len() as _, ids.as_mut_ptr())
+                },
+                _ => {
+                    self.record(GlCall::Other("gen_transform_feedbacks"));
+                    self.mock_ids(&mut ids);
+                },
+            }
+            ids[0]
+        }
+
+        pub fn delete_transform_feedbacks(&self, id: GLuint) {
+            forward_debug!(self.delete_transform_feedbacks(id));
+            let ids = vec![id];
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.DeleteTransformFeedbacks(ids.len() as _, ids.as_ptr()) },
+                Gl::Gles(gles) => unsafe {
+                    gles.DeleteTransformFeedbacks(ids.len() as _, ids.as_ptr())
+                },
+                _ => self.record(GlCall::Other("delete_transform_feedbacks")),
+            }
+        }
+
+        pub fn is_transform_feedback(&self, id: GLuint) -> bool {
+            forward_debug!(self.is_transform_feedback(id));
+            TRUE == match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.IsTransformFeedback(id) },
+                Gl::Gles(gles) => unsafe { gles.IsTransformFeedback(id) },
+                _ => {
+                    self.record(GlCall::Other("is_transform_feedback"));
+                    FALSE
+                },
+            }
+        }
+
+        pub fn bind_transform_feedback(&self, target: GLenum, id: u32) {
+            forward_debug!(self.bind_transform_feedback(target, id));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.BindTransformFeedback(target, id) },
+                Gl::Gles(gles) => unsafe { gles.BindTransformFeedback(target, id) },
+                _ => self.record(GlCall::Other("bind_transform_feedback")),
+            }
+        }
+
+        pub fn begin_transform_feedback(&self, mode: GLenum) {
+            forward_debug!(self.begin_transform_feedback(mode));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.BeginTransformFeedback(mode) },
+                Gl::Gles(gles) => unsafe { gles.BeginTransformFeedback(mode) },
+                _ => self.record(GlCall::Other("begin_transform_feedback")),
+            }
+        }
+
+        pub fn end_transform_feedback(&self) {
+            forward_debug!(self.end_transform_feedback());
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.EndTransformFeedback() },
+                Gl::Gles(gles) => unsafe { gles.EndTransformFeedback() },
+                _ => self.record(GlCall::Other("end_transform_feedback")),
+            }
+        }
+
+        pub fn pause_transform_feedback(&self) {
+            forward_debug!(self.pause_transform_feedback());
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.PauseTransformFeedback() },
+                Gl::Gles(gles) => unsafe { gles.PauseTransformFeedback() },
+                _ => self.record(GlCall::Other("pause_transform_feedback")),
+            }
+        }
+
+        pub fn resume_transform_feedback(&self) {
+            forward_debug!(self.resume_transform_feedback());
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.ResumeTransformFeedback() },
+                Gl::Gles(gles) => unsafe { gles.ResumeTransformFeedback() },
+                _ => self.record(GlCall::Other("resume_transform_feedback")),
+            }
+        }
+
+        pub fn get_transform_feedback_varying(&self, program: GLuint, index: GLuint) -> (i32, u32, String) {
+            forward_debug!(self.get_transform_feedback_varying(program, index));
+            let mut length = 0;
+            let buf_size = 128;
+            let mut name = vec![0 as c_char; buf_size as usize];
+            let mut size = 0;
+            let mut ty = 0;
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.GetTransformFeedbackVarying(
+                        program,
+                        index,
+                        buf_size,
+                        &mut length,
+                        &mut size,
+                        &mut ty,
+                        name.as_mut_ptr(),
+                    )
+                },
+                Gl::Gles(gles) => unsafe {
+                    gles.GetTransformFeedbackVarying(
+                        program,
+                        index,
+                        buf_size,
+                        &mut length,
+                        &mut size,
+                        &mut ty,
+                        name.as_mut_ptr(),
+                    )
+                },
+                _ => self.record(GlCall::Other("get_transform_feedback_varying")),
+            }
+            let name: &[u8] = unsafe { std::slice::from_raw_parts(name.as_ptr() as _, length as usize) };
+            let name = String::from_utf8(name.to_vec()).unwrap();
+            (size, ty, name)
+        }
+
+        pub fn transform_feedback_varyings(&self, program: GLuint, varyings: &[String], buffer_mode: GLenum) {
+            forward_debug!(self.transform_feedback_varyings(program, varyings, buffer_mode));
+            let c_varyings = varyings
+                .iter()
+                .map(|varying| {
+                    std::ffi::CString::new("_u".to_owned() + varying.as_str()).unwrap()
+                })
+                .collect::<Vec<_>>();
+            let pointers: Vec<*const c_char> =
+                c_varyings.iter().map(|p| p.as_ptr()).collect();
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe {
+                    gl.TransformFeedbackVaryings(
+                        program,
+                        varyings.len() as _,
+                        pointers.as_ptr() as _,
+                        buffer_mode,
+                    )
+                },
+                Gl::Gles(gles) => unsafe {
+                    gles.TransformFeedbackVaryings(
+                        program,
+                        varyings.len() as _,
+                        pointers.as_ptr() as _,
+                        buffer_mode,
+                    )
+                },
+                _ => self.record(GlCall::Other("transform_feedback_varyings")),
+            }
+        }
+
+        pub fn clear_buffer_iv(&self, buffer: GLenum, draw_buffer: GLint, value: &[GLint]) {
+            forward_debug!(self.clear_buffer_iv(buffer, draw_buffer, value));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.ClearBufferiv(buffer, draw_buffer, value.as_ptr()) },
+                Gl::Gles(gles) => unsafe {
+                    gles.ClearBufferiv(buffer, draw_buffer, value.as_ptr())
+                },
+                _ => self.record(GlCall::Other("clear_buffer_iv")),
+            }
+        }
+
+        pub fn clear_buffer_uiv(&self, buffer: GLenum, draw_buffer: GLint, value: &[GLuint]) {
+            forward_debug!(self.clear_buffer_uiv(buffer, draw_buffer, value));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.ClearBufferuiv(buffer, draw_buffer, value.as_ptr()) },
+                Gl::Gles(gles) => unsafe {
+                    gles.ClearBufferuiv(buffer, draw_buffer, value.as_ptr())
+                },
+                _ => self.record(GlCall::Other("clear_buffer_uiv")),
+            }
+        }
+
+        pub fn clear_buffer_fv(&self, buffer: GLenum, draw_buffer: GLint, value: &[GLfloat]) {
+            forward_debug!(self.clear_buffer_fv(buffer, draw_buffer, value));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.ClearBufferfv(buffer, draw_buffer, value.as_ptr()) },
+                Gl::Gles(gles) => unsafe {
+                    gles.ClearBufferfv(buffer, draw_buffer, value.as_ptr())
+                },
+                _ => self.record(GlCall::Other("clear_buffer_fv")),
+            }
+        }
+
+        pub fn clear_buffer_fi(
+            &self,
+            buffer: GLenum,
+            draw_buffer: GLint,
+            depth: GLfloat,
+            stencil: GLint,
+        ) {
+            forward_debug!(self.clear_buffer_fi(buffer, draw_buffer, depth, stencil));
+            match self {
+                #[cfg(not(feature = "gles-only"))]
+                Gl::Gl(gl) => unsafe { gl.ClearBufferfi(buffer, draw_buffer, depth, stencil) },
+                Gl::Gles(gles) => unsafe {
+                    gles.ClearBufferfi(buffer, draw_buffer, depth, stencil)
+                },
+                _ => self.record(GlCall::Other("clear_buffer_fi")),
+            }
+        }
+    }
+
+    /// The public method surface of `Gl` as a trait, so callers can take a
+    /// `&dyn GlApi` and substitute their own implementation in tests. Every
+    /// method forwards to the context returned by `gl` unless overridden.
+    /// Generic methods and those returning borrowing guards are left out, as
+    /// they can't be made into trait objects; see the inherent methods for
+    /// docs.
+    pub trait GlApi {
+        /// The context that methods which aren't overridden forward to.
+        fn gl(&self) -> &Gl;
+
+        fn get_type(&self) -> GlType {
+            Gl::get_type(self.gl())
+        }
+
+        fn take_log(&self) -> Vec<GlCall> {
+            Gl::take_log(self.gl())
+        }
+
+        fn set_mock_string(&self, which: GLenum, value: &str) {
+            Gl::set_mock_string(self.gl(), which, value)
+        }
+
+        fn push_mock_error(&self, error: GLenum) {
+            Gl::push_mock_error(self.gl(), error)
+        }
+
+        fn gen_framebuffers(&self, n: GLsizei) -> Vec<GLuint> {
+            Gl::gen_framebuffers(self.gl(), n)
+        }
+
+        fn gen_framebuffers_into(&self, ids: &mut [GLuint]) {
+            Gl::gen_framebuffers_into(self.gl(), ids)
+        }
+
+        fn gen_textures(&self, n: GLsizei) -> Vec<GLuint> {
+            Gl::gen_textures(self.gl(), n)
+        }
+
+        fn gen_textures_into(&self, ids: &mut [GLuint]) {
+            Gl::gen_textures_into(self.gl(), ids)
+        }
+
+        fn gen_renderbuffers(&self, n: GLsizei) -> Vec<GLuint> {
+            Gl::gen_renderbuffers(self.gl(), n)
+        }
+
+        fn gen_renderbuffers_into(&self, ids: &mut [GLuint]) {
+            Gl::gen_renderbuffers_into(self.gl(), ids)
+        }
+
+        fn gen_buffers(&self, n: GLsizei) -> Vec<GLuint> {
+            Gl::gen_buffers(self.gl(), n)
+        }
+
+        fn gen_buffers_into(&self, ids: &mut [GLuint]) {
+            Gl::gen_buffers_into(self.gl(), ids)
+        }
+
+        fn gen_vertex_arrays(&self, n: GLsizei) -> Vec<GLuint> {
+            Gl::gen_vertex_arrays(self.gl(), n)
+        }
+
+        fn gen_vertex_arrays_into(&self, ids: &mut [GLuint]) {
+            Gl::gen_vertex_arrays_into(self.gl(), ids)
+        }
+
+        fn shader_source(&self, shader: GLuint, strings: &[&[u8]]) {
+            Gl::shader_source(self.gl(), shader, strings)
+        }
+
+        fn shader_source_str(&self, shader: GLuint, source: &str) {
+            Gl::shader_source_str(self.gl(), shader, source)
+        }
+
+        fn create_program(&self) -> GLuint {
+            Gl::create_program(self.gl())
+        }
+
+        fn tex_image_2d(
+            &self,
+            target: GLenum,
+            level: GLint,
+            internal_format: GLint,
+            width: GLsizei,
+            height: GLsizei,
+            border: GLint,
+            format: GLenum,
+            ty: GLenum,
+            source: TexImageSource,
+        ) {
+            Gl::tex_image_2d(
+                self.gl(),
+                target,
+                level,
+                internal_format,
+                width,
+                height,
+                border,
+                format,
+                ty,
+                source,
+            )
+        }
+
+        fn upload_texture_2d_with_options(
+            &self,
+            target: GLenum,
+            level: GLint,
+            internal_format: GLint,
+            width: GLsizei,
+            height: GLsizei,
+            format: GLenum,
+            ty: GLenum,
+            pixels: &[u8],
+            flip_y: bool,
+            premultiply: bool,
+        ) {
+            Gl::upload_texture_2d_with_options(
+                self.gl(),
+                target,
+                level,
+                internal_format,
+                width,
+                height,
+                format,
+                ty,
+                pixels,
+                flip_y,
+                premultiply,
+            )
+        }
+
+        fn tex_sub_image_2d(
+            &self,
+            target: GLenum,
+            level: GLint,
+            xoffset: GLint,
+            yoffset: GLint,
+            width: GLsizei,
+            height: GLsizei,
+            format: GLenum,
+            ty: GLenum,
+            data: &[u8],
+        ) {
+            Gl::tex_sub_image_2d(
+                self.gl(),
+                target,
+                level,
+                xoffset,
+                yoffset,
+                width,
+                height,
+                format,
+                ty,
+                data,
+            )
+        }
+
+        fn copy_tex_image_2d(
+            &self,
+            target: GLenum,
+            level: GLint,
+            internal_format: GLenum,
+            x: GLint,
+            y: GLint,
+            width: GLsizei,
+            height: GLsizei,
+            border: GLint,
+        ) {
+            Gl::copy_tex_image_2d(
+                self.gl(),
+                target,
+                level,
+                internal_format,
+                x,
+                y,
+                width,
+                height,
+                border,
+            )
+        }
+
+        fn copy_tex_sub_image_2d(
+            &self,
+            target: GLenum,
+            level: GLint,
+            xoffset: GLint,
+            yoffset: GLint,
+            x: GLint,
+            y: GLint,
+            width: GLsizei,
+            height: GLsizei,
+        ) {
+            Gl::copy_tex_sub_image_2d(
+                self.gl(),
+                target,
+                level,
+                xoffset,
+                yoffset,
+                x,
+                y,
+                width,
+                height,
+            )
+        }
+
+        fn copy_image_sub_data(
+            &self,
+            src_name: GLuint,
+            src_target: GLenum,
+            src_level: GLint,
+            src_x: GLint,
+            src_y: GLint,
+            src_z: GLint,
+            dst_name: GLuint,
+            dst_target: GLenum,
+            dst_level: GLint,
+            dst_x: GLint,
+            dst_y: GLint,
+            dst_z: GLint,
+            width: GLsizei,
+            height: GLsizei,
+            depth: GLsizei,
+        ) {
+            Gl::copy_image_sub_data(
+                self.gl(),
+                src_name,
+                src_target,
+                src_level,
+                src_x,
+                src_y,
+                src_z,
+                dst_name,
+                dst_target,
+                dst_level,
+                dst_x,
+                dst_y,
+                dst_z,
+                width,
+                height,
+                depth,
+            )
+        }
+
+        fn compressed_tex_image_2d(
+            &self,
+            target: GLenum,
+            level: GLint,
+            internal_format: GLenum,
+            width: GLsizei,
+            height: GLsizei,
+            border: GLint,
+            data: &[u8],
+        ) {
+            Gl::compressed_tex_image_2d(
+                self.gl(),
+                target,
+                level,
+                internal_format,
+                width,
+                height,
+                border,
+                data,
+            )
+        }
+
+        fn compressed_tex_sub_image_2d(
+            &self,
+            target: GLenum,
+            level: GLint,
+            xoffset: GLint,
+            yoffset: GLint,
+            width: GLsizei,
+            height: GLsizei,
+            format: GLenum,
+            data: &[u8],
+        ) {
+            Gl::compressed_tex_sub_image_2d(
+                self.gl(),
+                target,
+                level,
+                xoffset,
+                yoffset,
+                width,
+                height,
+                format,
+                data,
+            )
+        }
+
+        fn tex_storage_2d(
+            &self,
+            target: GLenum,
+            levels: GLsizei,
+            internal_format: GLenum,
+            width: GLsizei,
+            height: GLsizei,
+        ) {
+            Gl::tex_storage_2d(self.gl(), target, levels, internal_format, width, height)
+        }
+
+        fn tex_storage_3d(
+            &self,
+            target: GLenum,
+            levels: GLsizei,
+            internal_format: GLenum,
+            width: GLsizei,
+            height: GLsizei,
+            depth: GLsizei,
+        ) {
+            Gl::tex_storage_3d(
+                self.gl(),
+                target,
+                levels,
+                internal_format,
+                width,
+                height,
+                depth,
+            )
+        }
+
+        fn create_texture_storage_2d(
+            &self,
+            internal_format: GLenum,
+            width: GLsizei,
+            height: GLsizei,
+            format: GLenum,
+            ty: GLenum,
+            pixels: Option<&[u8]>,
+            levels: GLsizei,
+        ) -> GLuint {
+            Gl::create_texture_storage_2d(
+                self.gl(),
+                internal_format,
+                width,
+                height,
+                format,
+                ty,
+                pixels,
+                levels,
+            )
+        }
+
+        fn create_shadow_map(
+            &self,
+            width: GLsizei,
+            height: GLsizei,
+            depth_format: GLenum,
+        ) -> Result<ShadowMap, String> {
+            Gl::create_shadow_map(self.gl(), width, height, depth_format)
+        }
+
+        fn generate_mipmap(&self, target: GLenum) {
+            Gl::generate_mipmap(self.gl(), target)
+        }
+
+        /// # Safety
+        ///
+        /// See `Gl::egl_image_target_texture_2d_oes`.
+        unsafe fn egl_image_target_texture_2d_oes(&self, target: GLenum, image: GLeglImageOES) {
+            Gl::egl_image_target_texture_2d_oes(self.gl(), target, image)
+        }
+
+        /// # Safety
+        ///
+        /// See `Gl::egl_image_target_renderbuffer_storage_oes`.
+        unsafe fn egl_image_target_renderbuffer_storage_oes(
+            &self,
+            target: GLenum,
+            image: GLeglImageOES,
+        ) {
+            Gl::egl_image_target_renderbuffer_storage_oes(self.gl(), target, image)
+        }
+
+        fn active_texture(&self, texture: GLenum) {
+            Gl::active_texture(self.gl(), texture)
+        }
+
+        fn attach_shader(&self, program: GLuint, shader: GLuint) {
+            Gl::attach_shader(self.gl(), program, shader)
+        }
+
+        fn create_shader(&self, shader_type: GLenum) -> GLuint {
+            Gl::create_shader(self.gl(), shader_type)
+        }
+
+        fn compile_shader_checked(
+            &self,
+            shader_type: GLenum,
+            sources: &[&[u8]],
+        ) -> Result<GLuint, String> {
+            Gl::compile_shader_checked(self.gl(), shader_type, sources)
+        }
+
+        fn delete_shader(&self, shader: GLuint) {
+            Gl::delete_shader(self.gl(), shader)
+        }
+
+        fn detach_shader(&self, program: GLuint, shader: GLuint) {
+            Gl::detach_shader(self.gl(), program, shader)
+        }
+
+        fn get_attached_shaders(&self, program: GLuint) -> Vec<GLuint> {
+            Gl::get_attached_shaders(self.gl(), program)
+        }
+
+        fn bind_buffer(&self, target: GLenum, buffer: GLuint) {
+            Gl::bind_buffer(self.gl(), target, buffer)
+        }
+
+        fn bind_buffer_typed(&self, target: BufferTarget, buffer: GLuint) {
+            Gl::bind_buffer_typed(self.gl(), target, buffer)
+        }
+
+        fn delete_buffers(&self, buffers: &[GLuint]) {
+            Gl::delete_buffers(self.gl(), buffers)
+        }
+
+        fn copy_buffer_sub_data(
+            &self,
+            read_target: u32,
+            write_target: u32,
+            read_offset: isize,
+            write_offset: isize,
+            size: isize,
+        ) {
+            Gl::copy_buffer_sub_data(
+                self.gl(),
+                read_target,
+                write_target,
+                read_offset,
+                write_offset,
+                size,
+            )
+        }
+
+        fn map_buffer_range(
+            &self,
+            target: GLenum,
+            offset: GLintptr,
+            length: GLsizeiptr,
+            access: GLbitfield,
+        ) -> *mut c_void {
+            Gl::map_buffer_range(self.gl(), target, offset, length, access)
+        }
+
+        fn flush_mapped_buffer_range(&self, target: GLenum, offset: GLintptr, length: GLsizeiptr) {
+            Gl::flush_mapped_buffer_range(self.gl(), target, offset, length)
+        }
+
+        fn unmap_buffer(&self, target: GLenum) {
+            Gl::unmap_buffer(self.gl(), target)
+        }
+
+        fn link_program(&self, program: GLuint) {
+            Gl::link_program(self.gl(), program)
+        }
+
+        fn link_program_checked(&self, program: GLuint) -> Result<(), String> {
+            Gl::link_program_checked(self.gl(), program)
+        }
+
+        fn validate_program(&self, program: GLuint) {
+            Gl::validate_program(self.gl(), program)
+        }
+
+        fn delete_program(&self, program: GLuint) {
+            Gl::delete_program(self.gl(), program)
+        }
+
+        fn delete_vertex_arrays(&self, vertex_arrays: &[GLuint]) {
+            Gl::delete_vertex_arrays(self.gl(), vertex_arrays)
+        }
+
+        fn bind_vertex_array(&self, vao: GLuint) {
+            Gl::bind_vertex_array(self.gl(), vao)
+        }
+
+        fn enable_vertex_attrib_array(&self, index: GLuint) {
+            Gl::enable_vertex_attrib_array(self.gl(), index)
+        }
+
+        fn disable_vertex_attrib_array(&self, index: GLuint) {
+            Gl::disable_vertex_attrib_array(self.gl(), index)
+        }
+
+        fn vertex_attrib_4f(&self, index: GLuint, x: GLfloat, y: GLfloat, z: GLfloat, w: GLfloat) {
+            Gl::vertex_attrib_4f(self.gl(), index, x, y, z, w)
+        }
+
+        fn vertex_attrib_4i(&self, index: GLuint, x: GLint, y: GLint, z: GLint, w: GLint) {
+            Gl::vertex_attrib_4i(self.gl(), index, x, y, z, w)
+        }
+
+        fn vertex_attrib_4ui(&self, index: GLuint, x: GLuint, y: GLuint, z: GLuint, w: GLuint) {
+            Gl::vertex_attrib_4ui(self.gl(), index, x, y, z, w)
+        }
+
+        fn vertex_attrib_1f(&self, index: GLuint, x: GLfloat) {
+            Gl::vertex_attrib_1f(self.gl(), index, x)
+        }
+
+        fn vertex_attrib_2f(&self, index: GLuint, x: GLfloat, y: GLfloat) {
+            Gl::vertex_attrib_2f(self.gl(), index, x, y)
+        }
+
+        fn vertex_attrib_3f(&self, index: GLuint, x: GLfloat, y: GLfloat, z: GLfloat) {
+            Gl::vertex_attrib_3f(self.gl(), index, x, y, z)
+        }
+
+        fn vertex_attrib_4fv(&self, index: GLuint, values: &[GLfloat; 4]) {
+            Gl::vertex_attrib_4fv(self.gl(), index, values)
+        }
+
+        fn vertex_attrib_i4iv(&self, index: GLuint, values: &[GLint; 4]) {
+            Gl::vertex_attrib_i4iv(self.gl(), index, values)
+        }
+
+        fn vertex_attrib_i4uiv(&self, index: GLuint, values: &[GLuint; 4]) {
+            Gl::vertex_attrib_i4uiv(self.gl(), index, values)
+        }
+
+        fn vertex_attrib_pointer_f32(
+            &self,
+            index: GLuint,
+            size: GLint,
+            normalized: bool,
+            stride: GLsizei,
+            offset: GLuint,
+        ) {
+            Gl::vertex_attrib_pointer_f32(self.gl(), index, size, normalized, stride, offset)
+        }
+
+        fn vertex_attrib_pointer(
+            &self,
+            index: GLuint,
+            size: GLint,
+            type_: GLenum,
+            normalized: bool,
+            stride: GLsizei,
+            offset: GLuint,
+        ) {
+            Gl::vertex_attrib_pointer(self.gl(), index, size, type_, normalized, stride, offset)
+        }
+
+        fn vertex_attrib_i_pointer(
+            &self,
+            index: GLuint,
+            size: GLint,
+            type_: GLenum,
+            stride: GLsizei,
+            offset: GLuint,
+        ) {
+            Gl::vertex_attrib_i_pointer(self.gl(), index, size, type_, stride, offset)
+        }
+
+        fn vertex_attrib_divisor(&self, index: GLuint, divisor: GLuint) {
+            Gl::vertex_attrib_divisor(self.gl(), index, divisor)
+        }
+
+        fn bind_vertex_buffer(
+            &self,
+            binding_index: GLuint,
+            buffer: GLuint,
+            offset: GLintptr,
+            stride: GLsizei,
+        ) {
+            Gl::bind_vertex_buffer(self.gl(), binding_index, buffer, offset, stride)
+        }
+
+        fn vertex_attrib_format(
+            &self,
+            index: GLuint,
+            size: GLint,
+            type_: GLenum,
+            normalized: bool,
+            relative_offset: GLuint,
+        ) {
+            Gl::vertex_attrib_format(self.gl(), index, size, type_, normalized, relative_offset)
+        }
+
+        fn vertex_attrib_i_format(
+            &self,
+            index: GLuint,
+            size: GLint,
+            type_: GLenum,
+            relative_offset: GLuint,
+        ) {
+            Gl::vertex_attrib_i_format(self.gl(), index, size, type_, relative_offset)
+        }
+
+        fn vertex_attrib_binding(&self, index: GLuint, binding_index: GLuint) {
+            Gl::vertex_attrib_binding(self.gl(), index, binding_index)
+        }
+
+        fn vertex_binding_divisor(&self, binding_index: GLuint, divisor: GLuint) {
+            Gl::vertex_binding_divisor(self.gl(), binding_index, divisor)
+        }
+
+        fn bind_attrib_location(&self, program: GLuint, index: GLuint, name: &str) {
+            Gl::bind_attrib_location(self.gl(), program, index, name)
+        }
+
+        /// # Safety
+        ///
+        /// See `Gl::get_uniform_iv`.
+        unsafe fn get_uniform_iv(&self, program: GLuint, location: GLint, result: &mut [GLint]) {
+            Gl::get_uniform_iv(self.gl(), program, location, result)
+        }
+
+        /// # Safety
+        ///
+        /// See `Gl::get_uniform_uiv`.
+        unsafe fn get_uniform_uiv(&self, program: GLuint, location: GLint, result: &mut [GLuint]) {
+            Gl::get_uniform_uiv(self.gl(), program, location, result)
+        }
+
+        /// # Safety
+        ///
+        /// See `Gl::get_uniform_fv`.
+        unsafe fn get_uniform_fv(&self, program: GLuint, location: GLint, result: &mut [GLfloat]) {
+            Gl::get_uniform_fv(self.gl(), program, location, result)
+        }
+
+        fn hint(&self, param_name: GLenum, param_val: GLenum) {
+            Gl::hint(self.gl(), param_name, param_val)
+        }
+
+        fn blend_color(&self, r: f32, g: f32, b: f32, a: f32) {
+            Gl::blend_color(self.gl(), r, g, b, a)
+        }
+
+        fn blend_func(&self, sfactor: GLenum, dfactor: GLenum) {
+            Gl::blend_func(self.gl(), sfactor, dfactor)
+        }
+
+        fn blend_func_separate(
+            &self,
+            src_rgb: GLenum,
+            dest_rgb: GLenum,
+            src_alpha: GLenum,
+            dest_alpha: GLenum,
+        ) {
+            Gl::blend_func_separate(self.gl(), src_rgb, dest_rgb, src_alpha, dest_alpha)
+        }
+
+        fn logic_op(&self, opcode: GLenum) {
+            Gl::logic_op(self.gl(), opcode)
+        }
+
+        fn blend_equation(&self, mode: GLenum) {
+            Gl::blend_equation(self.gl(), mode)
+        }
+
+        fn blend_equation_separate(&self, mode_rgb: GLenum, mode_alpha: GLenum) {
+            Gl::blend_equation_separate(self.gl(), mode_rgb, mode_alpha)
+        }
+
+        fn blend_func_i(&self, buf: GLuint, sfactor: GLenum, dfactor: GLenum) {
+            Gl::blend_func_i(self.gl(), buf, sfactor, dfactor)
+        }
+
+        fn blend_func_separate_i(
+            &self,
+            buf: GLuint,
+            src_rgb: GLenum,
+            dest_rgb: GLenum,
+            src_alpha: GLenum,
+            dest_alpha: GLenum,
+        ) {
+            Gl::blend_func_separate_i(self.gl(), buf, src_rgb, dest_rgb, src_alpha, dest_alpha)
+        }
+
+        fn blend_equation_i(&self, buf: GLuint, mode: GLenum) {
+            Gl::blend_equation_i(self.gl(), buf, mode)
+        }
+
+        fn blend_equation_separate_i(&self, buf: GLuint, mode_rgb: GLenum, mode_alpha: GLenum) {
+            Gl::blend_equation_separate_i(self.gl(), buf, mode_rgb, mode_alpha)
+        }
+
+        fn set_alpha_blend(&self) {
+            Gl::set_alpha_blend(self.gl())
+        }
+
+        fn set_premultiplied_blend(&self) {
+            Gl::set_premultiplied_blend(self.gl())
+        }
+
+        fn set_additive_blend(&self) {
+            Gl::set_additive_blend(self.gl())
+        }
+
+        fn color_mask(&self, r: bool, g: bool, b: bool, a: bool) {
+            Gl::color_mask(self.gl(), r, g, b, a)
+        }
+
+        fn color_mask_i(&self, buf: GLuint, r: bool, g: bool, b: bool, a: bool) {
+            Gl::color_mask_i(self.gl(), buf, r, g, b, a)
+        }
+
+        fn provoking_vertex(&self, mode: ProvokingVertex) {
+            Gl::provoking_vertex(self.gl(), mode)
+        }
+
+        fn primitive_restart_index(&self, index: GLuint) {
+            Gl::primitive_restart_index(self.gl(), index)
+        }
+
+        fn get_provoking_vertex(&self) -> GLenum {
+            Gl::get_provoking_vertex(self.gl())
+        }
+
+        fn cull_face(&self, mode: GLenum) {
+            Gl::cull_face(self.gl(), mode)
+        }
+
+        fn front_face(&self, mode: GLenum) {
+            Gl::front_face(self.gl(), mode)
+        }
+
+        fn depth_func(&self, func: GLenum) {
+            Gl::depth_func(self.gl(), func)
+        }
+
+        fn depth_mask(&self, flag: bool) {
+            Gl::depth_mask(self.gl(), flag)
+        }
+
+        fn depth_range(&self, near: f64, far: f64) {
+            Gl::depth_range(self.gl(), near, far)
+        }
+
+        fn depth_range_f(&self, near: f32, far: f32) {
+            Gl::depth_range_f(self.gl(), near, far)
+        }
+
+        fn depth_bounds(&self, zmin: f64, zmax: f64) {
+            Gl::depth_bounds(self.gl(), zmin, zmax)
+        }
+
+        fn line_width(&self, width: GLfloat) {
+            Gl::line_width(self.gl(), width)
+        }
+
+        fn point_size(&self, size: GLfloat) {
+            Gl::point_size(self.gl(), size)
+        }
+
+        fn patch_parameter_i(&self, pname: GLenum, value: GLint) {
+            Gl::patch_parameter_i(self.gl(), pname, value)
+        }
+
+        fn polygon_offset(&self, factor: GLfloat, units: GLfloat) {
+            Gl::polygon_offset(self.gl(), factor, units)
+        }
+
+        fn polygon_mode(&self, face: GLenum, mode: GLenum) {
+            Gl::polygon_mode(self.gl(), face, mode)
+        }
+
+        fn sample_coverage(&self, value: GLclampf, invert: bool) {
+            Gl::sample_coverage(self.gl(), value, invert)
+        }
+
+        fn get_multisample_fv(&self, pname: GLenum, index: GLuint) -> [GLfloat; 2] {
+            Gl::get_multisample_fv(self.gl(), pname, index)
+        }
+
+        fn sample_maski(&self, mask_number: GLuint, mask: GLbitfield) {
+            Gl::sample_maski(self.gl(), mask_number, mask)
+        }
+
+        fn min_sample_shading(&self, value: GLclampf) {
+            Gl::min_sample_shading(self.gl(), value)
+        }
+
+        fn clear_color(&self, r: f32, g: f32, b: f32, a: f32) {
+            Gl::clear_color(self.gl(), r, g, b, a)
+        }
+
+        fn clear_depth(&self, depth: f64) {
+            Gl::clear_depth(self.gl(), depth)
+        }
+
+        fn clear_depth_f(&self, depth: f32) {
+            Gl::clear_depth_f(self.gl(), depth)
+        }
+
+        fn clear_stencil(&self, s: GLint) {
+            Gl::clear_stencil(self.gl(), s)
+        }
+
+        fn clear(&self, buffer_mask: GLbitfield) {
+            Gl::clear(self.gl(), buffer_mask)
+        }
+
+        fn scissor(&self, x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+            Gl::scissor(self.gl(), x, y, width, height)
+        }
+
+        fn stencil_op(&self, sfail: GLenum, dpfail: GLenum, dppass: GLenum) {
+            Gl::stencil_op(self.gl(), sfail, dpfail, dppass)
+        }
+
+        fn stencil_op_separate(&self, face: GLenum, sfail: GLenum, dpfail: GLenum, dppass: GLenum) {
+            Gl::stencil_op_separate(self.gl(), face, sfail, dpfail, dppass)
+        }
+
+        fn stencil_mask(&self, mask: GLuint) {
+            Gl::stencil_mask(self.gl(), mask)
+        }
+
+        fn stencil_mask_separate(&self, face: GLenum, mask: GLuint) {
+            Gl::stencil_mask_separate(self.gl(), face, mask)
+        }
+
+        fn stencil_func(&self, func: GLenum, ref_: GLint, mask: GLuint) {
+            Gl::stencil_func(self.gl(), func, ref_, mask)
+        }
+
+        fn stencil_func_separate(&self, face: GLenum, func: GLenum, ref_: GLint, mask: GLuint) {
+            Gl::stencil_func_separate(self.gl(), face, func, ref_, mask)
+        }
+
+        fn is_enabled(&self, cap: GLenum) -> bool {
+            Gl::is_enabled(self.gl(), cap)
+        }
+
+        fn enable(&self, cap: GLenum) {
+            Gl::enable(self.gl(), cap)
+        }
+
+        fn disable(&self, cap: GLenum) {
+            Gl::disable(self.gl(), cap)
+        }
+
+        fn is_enabled_i(&self, cap: GLenum, index: GLuint) -> bool {
+            Gl::is_enabled_i(self.gl(), cap, index)
+        }
+
+        fn enable_i(&self, cap: GLenum, index: GLuint) {
+            Gl::enable_i(self.gl(), cap, index)
+        }
+
+        fn disable_i(&self, cap: GLenum, index: GLuint) {
+            Gl::disable_i(self.gl(), cap, index)
+        }
+
+        fn set_rasterizer_discard(&self, enabled: bool) {
+            Gl::set_rasterizer_discard(self.gl(), enabled)
+        }
+
+        fn is_rasterizer_discard_enabled(&self) -> bool {
+            Gl::is_rasterizer_discard_enabled(self.gl())
+        }
+
+        fn finish(&self) {
+            Gl::finish(self.gl())
+        }
+
+        fn flush(&self) {
+            Gl::flush(self.gl())
+        }
+
+        fn get_string(&self, which: GLenum) -> String {
+            Gl::get_string(self.gl(), which)
+        }
+
+        fn get_version(&self) -> (u32, u32) {
+            Gl::get_version(self.gl())
+        }
+
+        fn renderer(&self) -> String {
+            Gl::renderer(self.gl())
+        }
+
+        fn vendor(&self) -> String {
+            Gl::vendor(self.gl())
+        }
+
+        fn context_flags(&self) -> ContextFlags {
+            Gl::context_flags(self.gl())
+        }
+
+        fn is_core_profile(&self) -> bool {
+            Gl::is_core_profile(self.gl())
+        }
+
+        fn get_string_i(&self, which: GLenum, index: GLuint) -> String {
+            Gl::get_string_i(self.gl(), which, index)
+        }
+
+        fn extensions(&self) -> HashSet<String> {
+            Gl::extensions(self.gl())
+        }
+
+        fn has_extension(&self, name: &str) -> bool {
+            Gl::has_extension(self.gl(), name)
+        }
+
+        /// # Safety
+        ///
+        /// See `Gl::get_shader_iv`.
+        unsafe fn get_shader_iv(&self, shader: GLuint, pname: GLenum, result: &mut [GLint]) {
+            Gl::get_shader_iv(self.gl(), shader, pname, result)
+        }
+
+        fn get_shader_precision_format(
+            &self,
+            shader_type: GLuint,
+            precision_type: GLuint,
+        ) -> (GLint, GLint, GLint) {
+            Gl::get_shader_precision_format(self.gl(), shader_type, precision_type)
+        }
+
+        fn viewport(&self, x: GLint, y: GLint, width: GLsizei, height: GLsizei) {
+            Gl::viewport(self.gl(), x, y, width, height)
+        }
+
+        fn get_framebuffer_attachment_parameter_iv(
+            &self,
+            target: GLenum,
+            attachment: GLenum,
+            pname: GLenum,
+        ) -> GLint {
+            Gl::get_framebuffer_attachment_parameter_iv(self.gl(), target, attachment, pname)
+        }
+
+        fn get_internal_format_iv(
+            &self,
+            target: GLenum,
+            internalformat: GLenum,
+            pname: GLenum,
+            result: &mut [GLint],
+        ) {
+            Gl::get_internal_format_iv(self.gl(), target, internalformat, pname, result)
+        }
+
+        fn format_capabilities(&self, internal_format: GLenum) -> FormatCaps {
+            Gl::format_capabilities(self.gl(), internal_format)
+        }
+
+        fn get_renderbuffer_parameter_iv(&self, target: GLenum, pname: GLenum) -> GLint {
+            Gl::get_renderbuffer_parameter_iv(self.gl(), target, pname)
+        }
+
+        fn delete_renderbuffers(&self, buffers: &[GLuint]) {
+            Gl::delete_renderbuffers(self.gl(), buffers)
+        }
+
+        fn delete_textures(&self, textures: &[GLuint]) {
+            Gl::delete_textures(self.gl(), textures)
+        }
+
+        fn delete_framebuffers(&self, framebuffers: &[GLuint]) {
+            Gl::delete_framebuffers(self.gl(), framebuffers)
+        }
+
+        fn bind_renderbuffer(&self, target: GLenum, renderbuffer: GLuint) {
+            Gl::bind_renderbuffer(self.gl(), target, renderbuffer)
+        }
+
+        fn is_renderbuffer(&self, renderbuffer: GLuint) -> bool {
+            Gl::is_renderbuffer(self.gl(), renderbuffer)
+        }
+
+        fn bind_framebuffer(&self, target: GLenum, framebuffer: GLuint) {
+            Gl::bind_framebuffer(self.gl(), target, framebuffer)
+        }
+
+        fn is_framebuffer(&self, framebuffer: GLuint) -> bool {
+            Gl::is_framebuffer(self.gl(), framebuffer)
+        }
+
+        fn bind_texture(&self, target: GLenum, texture: GLuint) {
+            Gl::bind_texture(self.gl(), target, texture)
+        }
+
+        fn is_texture(&self, texture: GLuint) -> bool {
+            Gl::is_texture(self.gl(), texture)
+        }
+
+        fn is_shader(&self, shader: GLuint) -> bool {
+            Gl::is_shader(self.gl(), shader)
+        }
+
+        /// # Safety
+        ///
+        /// See `Gl::buffer_data`.
+        unsafe fn buffer_data(
+            &self,
+            target: GLenum,
+            size: GLsizeiptr,
+            data: *const GLvoid,
+            usage: GLenum,
+        ) {
+            Gl::buffer_data(self.gl(), target, size, data, usage)
+        }
+
+        fn buffer_storage(
+            &self,
+            target: GLenum,
+            size: GLsizeiptr,
+            data: Option<&[u8]>,
+            flags: GLbitfield,
+        ) {
+            Gl::buffer_storage(self.gl(), target, size, data, flags)
+        }
+
+        /// # Safety
+        ///
+        /// See `Gl::buffer_sub_data`.
+        unsafe fn buffer_sub_data(
+            &self,
+            target: GLenum,
+            offset: isize,
+            size: GLsizeiptr,
+            data: *const GLvoid,
+        ) {
+            Gl::buffer_sub_data(self.gl(), target, offset, size, data)
+        }
+
+        fn read_buffer(&self, buffer: GLenum) {
+            Gl::read_buffer(self.gl(), buffer)
+        }
+
+        fn draw_buffers(&self, bufs: &[GLenum]) {
+            Gl::draw_buffers(self.gl(), bufs)
+        }
+
+        fn draw_buffer(&self, buf: GLenum) {
+            Gl::draw_buffer(self.gl(), buf)
+        }
+
+        fn draw_arrays(&self, mode: GLenum, first: GLint, count: GLsizei) {
+            Gl::draw_arrays(self.gl(), mode, first, count)
+        }
+
+        fn draw_arrays_attributeless(&self, mode: GLenum, first: GLint, count: GLsizei) {
+            Gl::draw_arrays_attributeless(self.gl(), mode, first, count)
+        }
+
+        fn multi_draw_arrays(&self, mode: GLenum, firsts: &[GLint], counts: &[GLsizei]) {
+            Gl::multi_draw_arrays(self.gl(), mode, firsts, counts)
+        }
+
+        fn draw_arrays_indirect(&self, mode: GLenum, indirect_offset: GLuint) {
+            Gl::draw_arrays_indirect(self.gl(), mode, indirect_offset)
+        }
+
+        fn draw_arrays_instanced(
+            &self,
+            mode: GLenum,
+            first: GLint,
+            count: GLsizei,
+            primcount: GLsizei,
+        ) {
+            Gl::draw_arrays_instanced(self.gl(), mode, first, count, primcount)
+        }
+
+        fn draw_elements(
+            &self,
+            mode: GLenum,
+            count: GLsizei,
+            element_type: GLenum,
+            indices_offset: GLuint,
+        ) {
+            Gl::draw_elements(self.gl(), mode, count, element_type, indices_offset)
+        }
+
+        fn multi_draw_elements(
+            &self,
+            mode: GLenum,
+            counts: &[GLsizei],
+            element_type: GLenum,
+            indices_offsets: &[GLuint],
+        ) {
+            Gl::multi_draw_elements(self.gl(), mode, counts, element_type, indices_offsets)
+        }
+
+        fn draw_elements_indirect(
+            &self,
+            mode: GLenum,
+            element_type: GLenum,
+            indirect_offset: GLuint,
+        ) {
+            Gl::draw_elements_indirect(self.gl(), mode, element_type, indirect_offset)
+        }
+
+        fn dispatch_compute(
+            &self,
+            num_groups_x: GLuint,
+            num_groups_y: GLuint,
+            num_groups_z: GLuint,
+        ) {
+            Gl::dispatch_compute(self.gl(), num_groups_x, num_groups_y, num_groups_z)
+        }
+
+        fn dispatch_compute_indirect(&self, indirect_offset: GLintptr) {
+            Gl::dispatch_compute_indirect(self.gl(), indirect_offset)
+        }
+
+        fn memory_barrier(&self, barriers: GLbitfield) {
+            Gl::memory_barrier(self.gl(), barriers)
+        }
+
+        fn texture_barrier(&self) {
+            Gl::texture_barrier(self.gl())
+        }
+
+        fn bind_image_texture(
+            &self,
+            unit: GLuint,
+            texture: GLuint,
+            level: GLint,
+            layered: bool,
+            layer: GLint,
+            access: GLenum,
+            format: GLenum,
+        ) {
+            Gl::bind_image_texture(
+                self.gl(),
+                unit,
+                texture,
+                level,
+                layered,
+                layer,
+                access,
+                format,
+            )
+        }
+
+        fn draw_range_elements(
+            &self,
+            mode: GLenum,
+            start: GLuint,
+            end: GLuint,
+            count: GLsizei,
+            element_type: GLenum,
+            indices_offset: GLuint,
+        ) {
+            Gl::draw_range_elements(
+                self.gl(),
+                mode,
+                start,
+                end,
+                count,
+                element_type,
+                indices_offset,
+            )
+        }
+
+        fn draw_elements_base_vertex(
+            &self,
+            mode: GLenum,
+            count: GLsizei,
+            element_type: GLenum,
+            indices_offset: GLuint,
+            base_vertex: GLint,
+        ) {
+            Gl::draw_elements_base_vertex(
+                self.gl(),
+                mode,
+                count,
+                element_type,
+                indices_offset,
+                base_vertex,
+            )
+        }
+
+        fn draw_range_elements_base_vertex(
+            &self,
+            mode: GLenum,
+            start: GLuint,
+            end: GLuint,
+            count: GLsizei,
+            element_type: GLenum,
+            indices_offset: GLuint,
+            base_vertex: GLint,
+        ) {
+            Gl::draw_range_elements_base_vertex(
+                self.gl(),
+                mode,
+                start,
+                end,
+                count,
+                element_type,
+                indices_offset,
+                base_vertex,
+            )
+        }
+
+        fn draw_elements_instanced(
+            &self,
+            mode: GLenum,
+            count: GLsizei,
+            element_type: GLenum,
+            indices_offset: GLuint,
+            primcount: GLsizei,
+        ) {
+            Gl::draw_elements_instanced(
+                self.gl(),
+                mode,
+                count,
+                element_type,
+                indices_offset,
+                primcount,
+            )
+        }
+
+        fn framebuffer_renderbuffer(
+            &self,
+            target: GLenum,
+            attachment: GLenum,
+            renderbuffertarget: GLenum,
+            renderbuffer: GLuint,
+        ) {
+            Gl::framebuffer_renderbuffer(
+                self.gl(),
+                target,
+                attachment,
+                renderbuffertarget,
+                renderbuffer,
+            )
+        }
+
+        fn framebuffer_texture_2d(
+            &self,
+            target: GLenum,
+            attachment: GLenum,
+            textarget: GLenum,
+            texture: GLuint,
+            level: GLint,
+        ) {
+            Gl::framebuffer_texture_2d(self.gl(), target, attachment, textarget, texture, level)
+        }
+
+        fn framebuffer_texture_3d(
+            &self,
+            target: GLenum,
+            attachment: GLenum,
+            textarget: GLenum,
+            texture: GLuint,
+            level: GLint,
+            zoffset: GLint,
+        ) {
+            Gl::framebuffer_texture_3d(
+                self.gl(),
+                target,
+                attachment,
+                textarget,
+                texture,
+                level,
+                zoffset,
+            )
+        }
+
+        fn framebuffer_texture_layer(
+            &self,
+            target: GLenum,
+            attachment: GLenum,
+            texture: GLuint,
+            level: GLint,
+            layer: GLint,
+        ) {
+            Gl::framebuffer_texture_layer(self.gl(), target, attachment, texture, level, layer)
+        }
+
+        fn framebuffer_texture(
+            &self,
+            target: GLenum,
+            attachment: GLenum,
+            texture: GLuint,
+            level: GLint,
+        ) {
+            Gl::framebuffer_texture(self.gl(), target, attachment, texture, level)
+        }
+
+        fn framebuffer_parameter_i(&self, target: GLenum, pname: GLenum, param: GLint) {
+            Gl::framebuffer_parameter_i(self.gl(), target, pname, param)
+        }
+
+        fn get_framebuffer_parameter_iv(&self, target: GLenum, pname: GLenum) -> GLint {
+            Gl::get_framebuffer_parameter_iv(self.gl(), target, pname)
+        }
+
+        fn framebuffer_texture_multiview_ovr(
+            &self,
+            target: GLenum,
+            attachment: GLenum,
+            texture: GLuint,
+            level: GLint,
+            base_view_index: GLint,
+            num_views: GLsizei,
+        ) {
+            Gl::framebuffer_texture_multiview_ovr(
+                self.gl(),
+                target,
+                attachment,
+                texture,
+                level,
+                base_view_index,
+                num_views,
+            )
+        }
+
+        fn invalidate_framebuffer(&self, target: GLenum, attachments: &[GLenum]) {
+            Gl::invalidate_framebuffer(self.gl(), target, attachments)
+        }
+
+        fn invalidate_sub_framebuffer(
+            &self,
+            target: GLenum,
+            attachments: &[GLenum],
+            x: i32,
+            y: i32,
+            width: GLsizei,
+            height: GLsizei,
+        ) {
+            Gl::invalidate_sub_framebuffer(self.gl(), target, attachments, x, y, width, height)
+        }
+
+        fn invalidate_tex_image(&self, texture: GLuint, level: GLint) {
+            Gl::invalidate_tex_image(self.gl(), texture, level)
+        }
+
+        fn invalidate_tex_sub_image(
+            &self,
+            texture: GLuint,
+            level: GLint,
+            xoffset: GLint,
+            yoffset: GLint,
+            zoffset: GLint,
+            width: GLsizei,
+            height: GLsizei,
+            depth: GLsizei,
+        ) {
+            Gl::invalidate_tex_sub_image(
+                self.gl(),
+                texture,
+                level,
+                xoffset,
+                yoffset,
+                zoffset,
+                width,
+                height,
+                depth,
+            )
+        }
+
+        fn invalidate_buffer_data(&self, buffer: GLuint) {
+            Gl::invalidate_buffer_data(self.gl(), buffer)
+        }
+
+        fn invalidate_buffer_sub_data(&self, buffer: GLuint, offset: GLintptr, length: GLsizeiptr) {
+            Gl::invalidate_buffer_sub_data(self.gl(), buffer, offset, length)
+        }
+
+        fn renderbuffer_storage(
+            &self,
+            target: GLenum,
+            internalformat: GLenum,
+            width: GLsizei,
+            height: GLsizei,
+        ) {
+            Gl::renderbuffer_storage(self.gl(), target, internalformat, width, height)
+        }
+
+        fn renderbuffer_storage_multisample(
+            &self,
+            target: GLenum,
+            samples: GLsizei,
+            internalformat: GLenum,
+            width: GLsizei,
+            height: GLsizei,
+        ) {
+            Gl::renderbuffer_storage_multisample(
+                self.gl(),
+                target,
+                samples,
+                internalformat,
+                width,
+                height,
+            )
+        }
+
+        fn framebuffer_texture_2d_multisample(
+            &self,
+            target: GLenum,
+            attachment: GLenum,
+            textarget: GLenum,
+            texture: GLuint,
+            level: GLint,
+            samples: GLsizei,
+        ) {
+            Gl::framebuffer_texture_2d_multisample(
+                self.gl(),
+                target,
+                attachment,
+                textarget,
+                texture,
+                level,
+                samples,
+            )
+        }
+
+        fn renderbuffer_storage_multisample_ext(
+            &self,
+            target: GLenum,
+            samples: GLsizei,
+            internalformat: GLenum,
+            width: GLsizei,
+            height: GLsizei,
+        ) {
+            Gl::renderbuffer_storage_multisample_ext(
+                self.gl(),
+                target,
+                samples,
+                internalformat,
+                width,
+                height,
+            )
+        }
+
+        fn check_framebuffer_status(&self, target: GLenum) -> GLenum {
+            Gl::check_framebuffer_status(self.gl(), target)
+        }
+
+        fn diagnose_framebuffer(&self, target: GLenum) -> Result<(), String> {
+            Gl::diagnose_framebuffer(self.gl(), target)
+        }
+
+        fn get_error(&self) -> GLenum {
+            Gl::get_error(self.gl())
+        }
+
+        fn get_error_typed(&self) -> GlError {
+            Gl::get_error_typed(self.gl())
+        }
+
+        fn drain_errors(&self) -> Vec<GlError> {
+            Gl::drain_errors(self.gl())
+        }
+
+        fn get_graphics_reset_status(&self) -> GLenum {
+            Gl::get_graphics_reset_status(self.gl())
+        }
+
+        fn remove_debug_message_callback(&self) {
+            Gl::remove_debug_message_callback(self.gl())
+        }
+
+        fn debug_message_control(
+            &self,
+            source: GLenum,
+            type_: GLenum,
+            severity: GLenum,
+            ids: &[GLuint],
+            enabled: bool,
+        ) {
+            Gl::debug_message_control(self.gl(), source, type_, severity, ids, enabled)
+        }
+
+        fn debug_message_insert(
+            &self,
+            source: GLenum,
+            type_: GLenum,
+            id: GLuint,
+            severity: GLenum,
+            message: &str,
+        ) {
+            Gl::debug_message_insert(self.gl(), source, type_, id, severity, message)
+        }
+
+        fn object_label(&self, identifier: GLenum, name: GLuint, label: &str) {
+            Gl::object_label(self.gl(), identifier, name, label)
+        }
+
+        fn get_object_label(&self, identifier: GLenum, name: GLuint) -> String {
+            Gl::get_object_label(self.gl(), identifier, name)
+        }
+
+        fn tex_parameter_i(&self, target: GLenum, pname: GLenum, param: GLint) {
+            Gl::tex_parameter_i(self.gl(), target, pname, param)
+        }
+
+        fn set_depth_compare(&self, target: GLenum, func: GLenum) {
+            Gl::set_depth_compare(self.gl(), target, func)
+        }
+
+        fn disable_depth_compare(&self, target: GLenum) {
+            Gl::disable_depth_compare(self.gl(), target)
+        }
+
+        fn tex_parameter_f(&self, target: GLenum, pname: GLenum, param: GLfloat) {
+            Gl::tex_parameter_f(self.gl(), target, pname, param)
+        }
+
+        fn max_texture_max_anisotropy(&self) -> GLfloat {
+            Gl::max_texture_max_anisotropy(self.gl())
+        }
+
+        fn set_texture_max_anisotropy(&self, target: GLenum, value: GLfloat) {
+            Gl::set_texture_max_anisotropy(self.gl(), target, value)
+        }
+
+        fn get_tex_parameter_iv(&self, target: GLenum, pname: GLenum) -> GLint {
+            Gl::get_tex_parameter_iv(self.gl(), target, pname)
+        }
+
+        fn get_tex_parameter_fv(&self, target: GLenum, pname: GLenum) -> GLfloat {
+            Gl::get_tex_parameter_fv(self.gl(), target, pname)
+        }
+
+        fn get_tex_level_parameter_iv(&self, target: GLenum, level: GLint, pname: GLenum) -> GLint {
+            Gl::get_tex_level_parameter_iv(self.gl(), target, level, pname)
+        }
+
+        fn get_tex_level_parameter_fv(
+            &self,
+            target: GLenum,
+            level: GLint,
+            pname: GLenum,
+        ) -> GLfloat {
+            Gl::get_tex_level_parameter_fv(self.gl(), target, level, pname)
+        }
+
+        fn get_tex_image_into_buffer(
+            &self,
+            target: GLenum,
+            level: GLint,
+            format: GLenum,
+            ty: GLenum,
+            buffer: &mut [u8],
+        ) -> Result<(), TexImageError> {
+            Gl::get_tex_image_into_buffer(self.gl(), target, level, format, ty, buffer)
+        }
+
+        fn get_n_tex_image(
+            &self,
+            target: GLenum,
+            level: GLint,
+            format: GLenum,
+            ty: GLenum,
+            buffer: &mut [u8],
+        ) -> Result<(), TexImageError> {
+            Gl::get_n_tex_image(self.gl(), target, level, format, ty, buffer)
+        }
+
+        fn dump_texture(
+            &self,
+            target: GLenum,
+            level: GLint,
+            format: GLenum,
+            ty: GLenum,
+        ) -> Result<Vec<u8>, TexImageError> {
+            Gl::dump_texture(self.gl(), target, level, format, ty)
+        }
+
+        fn compressed_tex_image_size(&self, target: GLenum, level: GLint) -> GLint {
+            Gl::compressed_tex_image_size(self.gl(), target, level)
+        }
+
+        fn get_compressed_tex_image(&self, target: GLenum, level: GLint) -> Vec<u8> {
+            Gl::get_compressed_tex_image(self.gl(), target, level)
+        }
+
+        fn get_compressed_tex_image_into_buffer(
+            &self,
+            target: GLenum,
+            level: GLint,
+            buffer: &mut [u8],
+        ) {
+            Gl::get_compressed_tex_image_into_buffer(self.gl(), target, level, buffer)
+        }
+
+        fn get_active_attrib(&self, program: GLuint, index: GLuint) -> (i32, u32, String) {
+            Gl::get_active_attrib(self.gl(), program, index)
+        }
+
+        fn get_active_uniform(&self, program: GLuint, index: GLuint) -> (i32, u32, String) {
+            Gl::get_active_uniform(self.gl(), program, index)
+        }
+
+        fn get_attrib_location(&self, program: GLuint, name: &str) -> c_int {
+            Gl::get_attrib_location(self.gl(), program, name)
+        }
+
+        fn get_frag_data_location(&self, program: GLuint, name: &str) -> c_int {
+            Gl::get_frag_data_location(self.gl(), program, name)
+        }
+
+        fn bind_frag_data_location(&self, program: GLuint, color_number: GLuint, name: &str) {
+            Gl::bind_frag_data_location(self.gl(), program, color_number, name)
+        }
+
+        fn bind_frag_data_location_indexed(
+            &self,
+            program: GLuint,
+            color_number: GLuint,
+            index: GLuint,
+            name: &str,
+        ) {
+            Gl::bind_frag_data_location_indexed(self.gl(), program, color_number, index, name)
+        }
+
+        fn get_frag_data_index(&self, program: GLuint, name: &str) -> c_int {
+            Gl::get_frag_data_index(self.gl(), program, name)
+        }
+
+        fn get_uniform_location(&self, program: GLuint, name: &str) -> c_int {
+            Gl::get_uniform_location(self.gl(), program, name)
+        }
+
+        fn get_uniform_block_index(&self, program: GLuint, name: &str) -> GLuint {
+            Gl::get_uniform_block_index(self.gl(), program, name)
+        }
+
+        fn get_uniform_indices(&self, program: GLuint, names: &[&str]) -> Vec<GLuint> {
+            Gl::get_uniform_indices(self.gl(), program, names)
+        }
+
+        fn get_active_uniforms_iv(
+            &self,
+            program: GLuint,
+            uniforms: &[GLuint],
+            pname: GLenum,
+        ) -> Vec<GLint> {
+            Gl::get_active_uniforms_iv(self.gl(), program, uniforms, pname)
+        }
+
+        fn get_active_uniform_block_iv(
+            &self,
+            program: GLuint,
+            index: GLuint,
+            pname: GLenum,
+        ) -> Vec<GLint> {
+            Gl::get_active_uniform_block_iv(self.gl(), program, index, pname)
+        }
+
+        fn get_active_uniform_block_name(&self, program: GLuint, index: GLuint) -> String {
+            Gl::get_active_uniform_block_name(self.gl(), program, index)
+        }
+
+        fn uniform_block_binding(
+            &self,
+            program: GLuint,
+            uniform_block_index: GLuint,
+            uniform_block_binding: GLuint,
+        ) {
+            Gl::uniform_block_binding(
+                self.gl(),
+                program,
+                uniform_block_index,
+                uniform_block_binding,
+            )
+        }
+
+        fn set_uniform_block_binding_by_name(
+            &self,
+            program: GLuint,
+            name: &str,
+            binding: GLuint,
+        ) -> Result<GLuint, String> {
+            Gl::set_uniform_block_binding_by_name(self.gl(), program, name, binding)
+        }
+
+        fn auto_bind_uniform_blocks(&self, program: GLuint) -> Vec<(String, GLuint)> {
+            Gl::auto_bind_uniform_blocks(self.gl(), program)
+        }
+
+        fn get_program_interface_iv(
+            &self,
+            program: GLuint,
+            interface: GLenum,
+            pname: GLenum,
+        ) -> GLint {
+            Gl::get_program_interface_iv(self.gl(), program, interface, pname)
+        }
+
+        fn get_program_resource_index(
+            &self,
+            program: GLuint,
+            interface: GLenum,
+            name: &str,
+        ) -> GLuint {
+            Gl::get_program_resource_index(self.gl(), program, interface, name)
+        }
+
+        fn get_program_resource_name(
+            &self,
+            program: GLuint,
+            interface: GLenum,
+            index: GLuint,
+        ) -> String {
+            Gl::get_program_resource_name(self.gl(), program, interface, index)
+        }
+
+        fn get_program_resource_iv(
+            &self,
+            program: GLuint,
+            interface: GLenum,
+            index: GLuint,
+            props: &[GLenum],
+        ) -> Vec<GLint> {
+            Gl::get_program_resource_iv(self.gl(), program, interface, index, props)
+        }
+
+        fn bind_buffer_base(&self, program: GLenum, index: GLuint, buffer: GLuint) {
+            Gl::bind_buffer_base(self.gl(), program, index, buffer)
+        }
+
+        fn bind_buffer_range(
+            &self,
+            program: GLenum,
+            index: GLuint,
+            buffer: GLuint,
+            offset: GLintptr,
+            size: GLsizeiptr,
+        ) {
+            Gl::bind_buffer_range(self.gl(), program, index, buffer, offset, size)
+        }
+
+        fn bind_buffers_base(&self, target: GLenum, first: GLuint, buffers: &[GLuint]) {
+            Gl::bind_buffers_base(self.gl(), target, first, buffers)
+        }
+
+        fn bind_buffers_range(
+            &self,
+            target: GLenum,
+            first: GLuint,
+            buffers: &[GLuint],
+            offsets: &[GLintptr],
+            sizes: &[GLsizeiptr],
+        ) {
+            Gl::bind_buffers_range(self.gl(), target, first, buffers, offsets, sizes)
+        }
+
+        fn get_program_info_log(&self, program: GLuint) -> String {
+            Gl::get_program_info_log(self.gl(), program)
+        }
+
+        /// # Safety
+        ///
+        /// See `Gl::get_program_iv`.
+        unsafe fn get_program_iv(&self, program: GLuint, pname: GLenum, result: &mut [GLint]) {
+            Gl::get_program_iv(self.gl(), program, pname, result)
+        }
+
+        fn program_parameter_i(&self, program: GLuint, pname: GLenum, value: GLint) {
+            Gl::program_parameter_i(self.gl(), program, pname, value)
+        }
+
+        fn get_program_binary(&self, program: GLuint) -> (GLenum, Vec<u8>) {
+            Gl::get_program_binary(self.gl(), program)
+        }
+
+        fn program_binary(&self, program: GLuint, format: GLenum, binary: &[u8]) {
+            Gl::program_binary(self.gl(), program, format, binary)
+        }
+
+        /// # Safety
+        ///
+        /// See `Gl::get_vertex_attrib_fv`.
+        unsafe fn get_vertex_attrib_fv(
+            &self,
+            index: GLuint,
+            pname: GLenum,
+            result: &mut [GLfloat],
+        ) {
+            Gl::get_vertex_attrib_fv(self.gl(), index, pname, result)
+        }
+
+        /// # Safety
+        ///
+        /// See `Gl::get_vertex_attrib_iv`.
+        unsafe fn get_vertex_attrib_iv(&self, index: GLuint, pname: GLenum, result: &mut [GLint]) {
+            Gl::get_vertex_attrib_iv(self.gl(), index, pname, result)
+        }
+
+        fn get_vertex_attrib_pointer_v(&self, index: GLuint, pname: GLenum) -> isize {
+            Gl::get_vertex_attrib_pointer_v(self.gl(), index, pname)
+        }
+
+        fn get_shader_info_log(&self, shader: GLuint) -> String {
+            Gl::get_shader_info_log(self.gl(), shader)
+        }
+
+        fn get_shader_source(&self, shader: GLuint) -> String {
+            Gl::get_shader_source(self.gl(), shader)
+        }
+
+        /// # Safety
+        ///
+        /// See `Gl::get_integer_v`.
+        unsafe fn get_integer_v(&self, name: GLenum, result: &mut [GLint]) {
+            Gl::get_integer_v(self.gl(), name, result)
+        }
+
+        /// # Safety
+        ///
+        /// See `Gl::get_integer64_v`.
+        unsafe fn get_integer64_v(&self, name: GLenum, result: &mut [GLint64]) {
+            Gl::get_integer64_v(self.gl(), name, result)
+        }
+
+        /// # Safety
+        ///
+        /// See `Gl::get_integeri_v`.
+        unsafe fn get_integeri_v(&self, name: GLenum, index: GLuint, result: &mut [GLint]) {
+            Gl::get_integeri_v(self.gl(), name, index, result)
+        }
+
+        /// # Safety
+        ///
+        /// See `Gl::get_integer64i_v`.
+        unsafe fn get_integer64i_v(&self, name: GLenum, index: GLuint, result: &mut [GLint64]) {
+            Gl::get_integer64i_v(self.gl(), name, index, result)
+        }
+
+        fn get_integer(&self, name: GLenum) -> GLint {
+            Gl::get_integer(self.gl(), name)
+        }
+
+        fn get_integer_indexed(&self, name: GLenum, index: GLuint) -> GLint {
+            Gl::get_integer_indexed(self.gl(), name, index)
+        }
+
+        fn get_float(&self, name: GLenum) -> GLfloat {
+            Gl::get_float(self.gl(), name)
+        }
+
+        fn get_boolean(&self, name: GLenum) -> bool {
+            Gl::get_boolean(self.gl(), name)
+        }
+
+        /// # Safety
+        ///
+        /// See `Gl::get_boolean_v`.
+        unsafe fn get_boolean_v(&self, name: GLenum, result: &mut [GLboolean]) {
+            Gl::get_boolean_v(self.gl(), name, result)
+        }
+
+        /// # Safety
+        ///
+        /// See `Gl::get_float_v`.
+        unsafe fn get_float_v(&self, name: GLenum, result: &mut [GLfloat]) {
+            Gl::get_float_v(self.gl(), name, result)
+        }
+
+        fn max_fragment_uniform_vectors(&self) -> GLint {
+            Gl::max_fragment_uniform_vectors(self.gl())
+        }
+
+        fn max_vertex_uniform_vectors(&self) -> GLint {
+            Gl::max_vertex_uniform_vectors(self.gl())
+        }
+
+        fn max_draw_buffers(&self) -> GLint {
+            Gl::max_draw_buffers(self.gl())
+        }
+
+        fn max_texture_image_units(&self) -> GLint {
+            Gl::max_texture_image_units(self.gl())
+        }
+
+        fn max_vertex_texture_image_units(&self) -> GLint {
+            Gl::max_vertex_texture_image_units(self.gl())
+        }
+
+        fn compile_shader(&self, shader: GLuint) {
+            Gl::compile_shader(self.gl(), shader)
+        }
+
+        fn pixel_store_i(&self, name: GLenum, param: GLint) {
+            Gl::pixel_store_i(self.gl(), name, param)
+        }
+
+        fn read_pixels_into_buffer(
+            &self,
+            x: GLint,
+            y: GLint,
+            width: GLsizei,
+            height: GLsizei,
+            format: GLenum,
+            pixel_type: GLenum,
+            buffer: &mut [u8],
+        ) {
+            Gl::read_pixels_into_buffer(self.gl(), x, y, width, height, format, pixel_type, buffer)
+        }
+
+        fn read_pixels_into_buffer_with_stride(
+            &self,
+            x: GLint,
+            y: GLint,
+            width: GLsizei,
+            height: GLsizei,
+            format: GLenum,
+            pixel_type: GLenum,
+            row_length: GLsizei,
+            buffer: &mut [u8],
+        ) {
+            Gl::read_pixels_into_buffer_with_stride(
+                self.gl(),
+                x,
+                y,
+                width,
+                height,
+                format,
+                pixel_type,
+                row_length,
+                buffer,
+            )
+        }
+
+        fn read_n_pixels_into_buffer(
+            &self,
+            x: GLint,
+            y: GLint,
+            width: GLsizei,
+            height: GLsizei,
+            format: GLenum,
+            pixel_type: GLenum,
+            buffer: &mut [u8],
+        ) {
+            Gl::read_n_pixels_into_buffer(
+                self.gl(),
+                x,
+                y,
+                width,
+                height,
+                format,
+                pixel_type,
+                buffer,
+            )
+        }
+
+        /// # Safety
+        ///
+        /// See `Gl::read_pixels_into_pixel_pack_buffer`.
+        unsafe fn read_pixels_into_pixel_pack_buffer(
+            &self,
+            x: GLint,
+            y: GLint,
+            width: GLsizei,
+            height: GLsizei,
+            format: GLenum,
+            pixel_type: GLenum,
+            buffer_byte_offset: usize,
+        ) {
+            Gl::read_pixels_into_pixel_pack_buffer(
+                self.gl(),
+                x,
+                y,
+                width,
+                height,
+                format,
+                pixel_type,
+                buffer_byte_offset,
+            )
+        }
+
+        fn read_pixels(
+            &self,
+            x: GLint,
+            y: GLint,
+            width: GLsizei,
+            height: GLsizei,
+            format: GLenum,
+            pixel_type: GLenum,
+        ) -> Vec<u8> {
+            Gl::read_pixels(self.gl(), x, y, width, height, format, pixel_type)
+        }
+
+        fn try_read_pixels(
+            &self,
+            x: GLint,
+            y: GLint,
+            width: GLsizei,
+            height: GLsizei,
+            format: GLenum,
+            pixel_type: GLenum,
+        ) -> Result<Vec<u8>, UnsupportedPixelFormat> {
+            Gl::try_read_pixels(self.gl(), x, y, width, height, format, pixel_type)
+        }
+
+        fn read_pixels_fast(
+            &self,
+            x: GLint,
+            y: GLint,
+            width: GLsizei,
+            height: GLsizei,
+        ) -> Result<(GLenum, GLenum, Vec<u8>), UnsupportedPixelFormat> {
+            Gl::read_pixels_fast(self.gl(), x, y, width, height)
+        }
+
+        fn fence_sync(&self, condition: GLenum, flags: GLbitfield) -> GLsync {
+            Gl::fence_sync(self.gl(), condition, flags)
+        }
+
+        fn client_wait_sync(&self, sync: GLsync, flags: GLbitfield, timeout: GLuint64) -> GLenum {
+            Gl::client_wait_sync(self.gl(), sync, flags, timeout)
+        }
+
+        fn wait_sync(&self, sync: GLsync, flags: GLbitfield, timeout: GLuint64) {
+            Gl::wait_sync(self.gl(), sync, flags, timeout)
+        }
+
+        fn get_sync_iv(&self, sync: GLsync, pname: GLenum) -> Vec<GLint> {
+            Gl::get_sync_iv(self.gl(), sync, pname)
+        }
+
+        fn is_sync(&self, sync: GLsync) -> bool {
+            Gl::is_sync(self.gl(), sync)
+        }
+
+        fn delete_sync(&self, sync: GLsync) {
+            Gl::delete_sync(self.gl(), sync)
+        }
+
+        fn uniform_1f(&self, location: GLint, v0: GLfloat) {
+            Gl::uniform_1f(self.gl(), location, v0)
+        }
+
+        fn uniform_1fv(&self, location: GLint, values: &[f32]) {
+            Gl::uniform_1fv(self.gl(), location, values)
+        }
+
+        fn uniform_1i(&self, location: GLint, v0: GLint) {
+            Gl::uniform_1i(self.gl(), location, v0)
+        }
+
+        fn uniform_1iv(&self, location: GLint, values: &[i32]) {
+            Gl::uniform_1iv(self.gl(), location, values)
+        }
+
+        fn uniform_1ui(&self, location: GLint, v0: GLuint) {
+            Gl::uniform_1ui(self.gl(), location, v0)
+        }
+
+        fn uniform_1uiv(&self, location: GLint, values: &[u32]) {
+            Gl::uniform_1uiv(self.gl(), location, values)
+        }
+
+        fn uniform_2f(&self, location: GLint, v0: GLfloat, v1: GLfloat) {
+            Gl::uniform_2f(self.gl(), location, v0, v1)
+        }
+
+        fn uniform_2fv(&self, location: GLint, values: &[f32]) {
+            Gl::uniform_2fv(self.gl(), location, values)
+        }
+
+        fn uniform_2i(&self, location: GLint, v0: GLint, v1: GLint) {
+            Gl::uniform_2i(self.gl(), location, v0, v1)
+        }
+
+        fn uniform_2iv(&self, location: GLint, values: &[i32]) {
+            Gl::uniform_2iv(self.gl(), location, values)
+        }
+
+        fn uniform_2ui(&self, location: GLint, v0: GLuint, v1: GLuint) {
+            Gl::uniform_2ui(self.gl(), location, v0, v1)
+        }
+
+        fn uniform_2uiv(&self, location: GLint, values: &[u32]) {
+            Gl::uniform_2uiv(self.gl(), location, values)
+        }
+
+        fn uniform_3f(&self, location: GLint, v0: GLfloat, v1: GLfloat, v2: GLfloat) {
+            Gl::uniform_3f(self.gl(), location, v0, v1, v2)
+        }
+
+        fn uniform_3fv(&self, location: GLint, values: &[f32]) {
+            Gl::uniform_3fv(self.gl(), location, values)
+        }
+
+        fn uniform_3i(&self, location: GLint, v0: GLint, v1: GLint, v2: GLint) {
+            Gl::uniform_3i(self.gl(), location, v0, v1, v2)
+        }
+
+        fn uniform_3iv(&self, location: GLint, values: &[i32]) {
+            Gl::uniform_3iv(self.gl(), location, values)
+        }
+
+        fn uniform_3ui(&self, location: GLint, v0: GLuint, v1: GLuint, v2: GLuint) {
+            Gl::uniform_3ui(self.gl(), location, v0, v1, v2)
+        }
+
+        fn uniform_3uiv(&self, location: GLint, values: &[u32]) {
+            Gl::uniform_3uiv(self.gl(), location, values)
+        }
+
+        fn uniform_4f(&self, location: GLint, x: GLfloat, y: GLfloat, z: GLfloat, w: GLfloat) {
+            Gl::uniform_4f(self.gl(), location, x, y, z, w)
+        }
+
+        fn uniform_4i(&self, location: GLint, x: GLint, y: GLint, z: GLint, w: GLint) {
+            Gl::uniform_4i(self.gl(), location, x, y, z, w)
+        }
+
+        fn uniform_4iv(&self, location: GLint, values: &[i32]) {
+            Gl::uniform_4iv(self.gl(), location, values)
+        }
+
+        fn uniform_4ui(&self, location: GLint, x: GLuint, y: GLuint, z: GLuint, w: GLuint) {
+            Gl::uniform_4ui(self.gl(), location, x, y, z, w)
+        }
+
+        fn uniform_4uiv(&self, location: GLint, values: &[u32]) {
+            Gl::uniform_4uiv(self.gl(), location, values)
+        }
+
+        fn uniform_4fv(&self, location: GLint, values: &[f32]) {
+            Gl::uniform_4fv(self.gl(), location, values)
+        }
+
+        fn uniform_matrix_2fv(&self, location: GLint, transpose: bool, values: &[f32]) {
+            Gl::uniform_matrix_2fv(self.gl(), location, transpose, values)
+        }
+
+        fn uniform_matrix_3fv(&self, location: GLint, transpose: bool, values: &[f32]) {
+            Gl::uniform_matrix_3fv(self.gl(), location, transpose, values)
+        }
+
+        fn uniform_matrix_4fv(&self, location: GLint, transpose: bool, values: &[f32]) {
+            Gl::uniform_matrix_4fv(self.gl(), location, transpose, values)
+        }
+
+        fn uniform_matrix_3x2fv(&self, location: GLint, transpose: bool, values: &[f32]) {
+            Gl::uniform_matrix_3x2fv(self.gl(), location, transpose, values)
+        }
+
+        fn uniform_matrix_4x2fv(&self, location: GLint, transpose: bool, values: &[f32]) {
+            Gl::uniform_matrix_4x2fv(self.gl(), location, transpose, values)
+        }
+
+        fn uniform_matrix_2x3fv(&self, location: GLint, transpose: bool, values: &[f32]) {
+            Gl::uniform_matrix_2x3fv(self.gl(), location, transpose, values)
+        }
+
+        fn uniform_matrix_4x3fv(&self, location: GLint, transpose: bool, values: &[f32]) {
+            Gl::uniform_matrix_4x3fv(self.gl(), location, transpose, values)
+        }
+
+        fn uniform_matrix_2x4fv(&self, location: GLint, transpose: bool, values: &[f32]) {
+            Gl::uniform_matrix_2x4fv(self.gl(), location, transpose, values)
+        }
+
+        fn uniform_matrix_3x4fv(&self, location: GLint, transpose: bool, values: &[f32]) {
+            Gl::uniform_matrix_3x4fv(self.gl(), location, transpose, values)
+        }
+
+        fn use_program(&self, program: GLuint) {
+            Gl::use_program(self.gl(), program)
+        }
+
+        fn gen_program_pipelines(&self, n: GLsizei) -> Vec<GLuint> {
+            Gl::gen_program_pipelines(self.gl(), n)
+        }
+
+        fn delete_program_pipelines(&self, pipelines: &[GLuint]) {
+            Gl::delete_program_pipelines(self.gl(), pipelines)
+        }
+
+        fn bind_program_pipeline(&self, pipeline: GLuint) {
+            Gl::bind_program_pipeline(self.gl(), pipeline)
+        }
+
+        fn use_program_stages(&self, pipeline: GLuint, stages: GLbitfield, program: GLuint) {
+            Gl::use_program_stages(self.gl(), pipeline, stages, program)
+        }
+
+        fn program_uniform_1f(&self, program: GLuint, location: GLint, v0: GLfloat) {
+            Gl::program_uniform_1f(self.gl(), program, location, v0)
+        }
+
+        fn program_uniform_1i(&self, program: GLuint, location: GLint, v0: GLint) {
+            Gl::program_uniform_1i(self.gl(), program, location, v0)
+        }
+
+        fn program_uniform_1ui(&self, program: GLuint, location: GLint, v0: GLuint) {
+            Gl::program_uniform_1ui(self.gl(), program, location, v0)
+        }
+
+        fn program_uniform_1fv(&self, program: GLuint, location: GLint, values: &[f32]) {
+            Gl::program_uniform_1fv(self.gl(), program, location, values)
+        }
+
+        fn program_uniform_1iv(&self, program: GLuint, location: GLint, values: &[i32]) {
+            Gl::program_uniform_1iv(self.gl(), program, location, values)
+        }
+
+        fn program_uniform_1uiv(&self, program: GLuint, location: GLint, values: &[u32]) {
+            Gl::program_uniform_1uiv(self.gl(), program, location, values)
+        }
+
+        fn program_uniform_2f(&self, program: GLuint, location: GLint, v0: GLfloat, v1: GLfloat) {
+            Gl::program_uniform_2f(self.gl(), program, location, v0, v1)
+        }
+
+        fn program_uniform_2i(&self, program: GLuint, location: GLint, v0: GLint, v1: GLint) {
+            Gl::program_uniform_2i(self.gl(), program, location, v0, v1)
+        }
+
+        fn program_uniform_2ui(&self, program: GLuint, location: GLint, v0: GLuint, v1: GLuint) {
+            Gl::program_uniform_2ui(self.gl(), program, location, v0, v1)
+        }
+
+        fn program_uniform_2fv(&self, program: GLuint, location: GLint, values: &[f32]) {
+            Gl::program_uniform_2fv(self.gl(), program, location, values)
+        }
+
+        fn program_uniform_2iv(&self, program: GLuint, location: GLint, values: &[i32]) {
+            Gl::program_uniform_2iv(self.gl(), program, location, values)
+        }
+
+        fn program_uniform_2uiv(&self, program: GLuint, location: GLint, values: &[u32]) {
+            Gl::program_uniform_2uiv(self.gl(), program, location, values)
+        }
+
+        fn program_uniform_3f(
+            &self,
+            program: GLuint,
+            location: GLint,
+            v0: GLfloat,
+            v1: GLfloat,
+            v2: GLfloat,
+        ) {
+            Gl::program_uniform_3f(self.gl(), program, location, v0, v1, v2)
+        }
+
+        fn program_uniform_3i(
+            &self,
+            program: GLuint,
+            location: GLint,
+            v0: GLint,
+            v1: GLint,
+            v2: GLint,
+        ) {
+            Gl::program_uniform_3i(self.gl(), program, location, v0, v1, v2)
+        }
+
+        fn program_uniform_3ui(
+            &self,
+            program: GLuint,
+            location: GLint,
+            v0: GLuint,
+            v1: GLuint,
+            v2: GLuint,
+        ) {
+            Gl::program_uniform_3ui(self.gl(), program, location, v0, v1, v2)
+        }
+
+        fn program_uniform_3fv(&self, program: GLuint, location: GLint, values: &[f32]) {
+            Gl::program_uniform_3fv(self.gl(), program, location, values)
+        }
+
+        fn program_uniform_3iv(&self, program: GLuint, location: GLint, values: &[i32]) {
+            Gl::program_uniform_3iv(self.gl(), program, location, values)
+        }
+
+        fn program_uniform_3uiv(&self, program: GLuint, location: GLint, values: &[u32]) {
+            Gl::program_uniform_3uiv(self.gl(), program, location, values)
+        }
+
+        fn program_uniform_4f(
+            &self,
+            program: GLuint,
+            location: GLint,
+            v0: GLfloat,
+            v1: GLfloat,
+            v2: GLfloat,
+            v3: GLfloat,
+        ) {
+            Gl::program_uniform_4f(self.gl(), program, location, v0, v1, v2, v3)
+        }
+
+        fn program_uniform_4i(
+            &self,
+            program: GLuint,
+            location: GLint,
+            v0: GLint,
+            v1: GLint,
+            v2: GLint,
+            v3: GLint,
+        ) {
+            Gl::program_uniform_4i(self.gl(), program, location, v0, v1, v2, v3)
+        }
+
+        fn program_uniform_4ui(
+            &self,
+            program: GLuint,
+            location: GLint,
+            v0: GLuint,
+            v1: GLuint,
+            v2: GLuint,
+            v3: GLuint,
+        ) {
+            Gl::program_uniform_4ui(self.gl(), program, location, v0, v1, v2, v3)
+        }
+
+        fn program_uniform_4fv(&self, program: GLuint, location: GLint, values: &[f32]) {
+            Gl::program_uniform_4fv(self.gl(), program, location, values)
+        }
+
+        fn program_uniform_4iv(&self, program: GLuint, location: GLint, values: &[i32]) {
+            Gl::program_uniform_4iv(self.gl(), program, location, values)
+        }
+
+        fn program_uniform_4uiv(&self, program: GLuint, location: GLint, values: &[u32]) {
+            Gl::program_uniform_4uiv(self.gl(), program, location, values)
+        }
+
+        fn program_uniform_matrix_2fv(
+            &self,
+            program: GLuint,
+            location: GLint,
+            transpose: bool,
+            values: &[f32],
+        ) {
+            Gl::program_uniform_matrix_2fv(self.gl(), program, location, transpose, values)
+        }
+
+        fn program_uniform_matrix_3fv(
+            &self,
+            program: GLuint,
+            location: GLint,
+            transpose: bool,
+            values: &[f32],
+        ) {
+            Gl::program_uniform_matrix_3fv(self.gl(), program, location, transpose, values)
+        }
+
+        fn program_uniform_matrix_4fv(
+            &self,
+            program: GLuint,
+            location: GLint,
+            transpose: bool,
+            values: &[f32],
+        ) {
+            Gl::program_uniform_matrix_4fv(self.gl(), program, location, transpose, values)
+        }
+
+        fn blit_framebuffer(
+            &self,
+            src_x0: GLint,
+            src_y0: GLint,
+            src_x1: GLint,
+            src_y1: GLint,
+            dst_x0: GLint,
+            dst_y0: GLint,
+            dst_x1: GLint,
+            dst_y1: GLint,
+            mask: GLbitfield,
+            filter: GLenum,
+        ) {
+            Gl::blit_framebuffer(
+                self.gl(),
+                src_x0,
+                src_y0,
+                src_x1,
+                src_y1,
+                dst_x0,
+                dst_y0,
+                dst_x1,
+                dst_y1,
+                mask,
+                filter,
+            )
+        }
+
+        fn blit_depth(
+            &self,
+            src_rect: (GLint, GLint, GLint, GLint),
+            dst_rect: (GLint, GLint, GLint, GLint),
+        ) {
+            Gl::blit_depth(self.gl(), src_rect, dst_rect)
+        }
+
+        fn gen_queries(&self, n: GLsizei) -> Vec<GLuint> {
+            Gl::gen_queries(self.gl(), n)
+        }
+
+        fn supports_exact_occlusion_query(&self) -> bool {
+            Gl::supports_exact_occlusion_query(self.gl())
+        }
+
+        fn begin_query(&self, target: GLenum, id: GLuint) {
+            Gl::begin_query(self.gl(), target, id)
+        }
+
+        fn end_query(&self, target: GLenum) {
+            Gl::end_query(self.gl(), target)
+        }
+
+        fn query_counter(&self, id: GLuint, target: GLenum) {
+            Gl::query_counter(self.gl(), id, target)
+        }
+
+        fn delete_queries(&self, ids: &[GLuint]) {
+            Gl::delete_queries(self.gl(), ids)
+        }
+
+        fn is_query(&self, id: GLuint) -> bool {
+            Gl::is_query(self.gl(), id)
+        }
+
+        fn get_query_iv(&self, target: GLenum, pname: GLenum) -> i32 {
+            Gl::get_query_iv(self.gl(), target, pname)
+        }
+
+        fn get_query_object_iv(&self, id: GLuint, pname: GLenum) -> i32 {
+            Gl::get_query_object_iv(self.gl(), id, pname)
+        }
+
+        fn get_query_object_uiv(&self, id: GLuint, pname: GLenum) -> u32 {
+            Gl::get_query_object_uiv(self.gl(), id, pname)
+        }
+
+        fn get_query_object_i64v(&self, id: GLuint, pname: GLenum) -> i64 {
+            Gl::get_query_object_i64v(self.gl(), id, pname)
+        }
+
+        fn get_query_object_ui64v(&self, id: GLuint, pname: GLenum) -> u64 {
+            Gl::get_query_object_ui64v(self.gl(), id, pname)
+        }
+
+        fn get_query_result_u64(&self, id: GLuint) -> u64 {
+            Gl::get_query_result_u64(self.gl(), id)
+        }
+
+        fn gen_samplers(&self, n: GLsizei) -> Vec<GLuint> {
+            Gl::gen_samplers(self.gl(), n)
+        }
+
+        fn delete_samplers(&self, samplers: &[GLuint]) {
+            Gl::delete_samplers(self.gl(), samplers)
+        }
+
+        fn is_sampler(&self, sampler: GLuint) -> bool {
+            Gl::is_sampler(self.gl(), sampler)
+        }
+
+        fn bind_sampler(&self, target: GLenum, sampler: GLuint) {
+            Gl::bind_sampler(self.gl(), target, sampler)
+        }
+
+        fn get_sampler_parameter_iv(&self, sampler: GLuint, pname: GLenum) -> Vec<GLint> {
+            Gl::get_sampler_parameter_iv(self.gl(), sampler, pname)
+        }
+
+        fn get_sampler_parameter_fv(&self, sampler: GLuint, pname: GLenum) -> Vec<GLfloat> {
+            Gl::get_sampler_parameter_fv(self.gl(), sampler, pname)
+        }
+
+        fn sampler_parameter_i(&self, sampler: GLuint, pname: GLenum, param: GLint) {
+            Gl::sampler_parameter_i(self.gl(), sampler, pname, param)
+        }
+
+        fn sampler_parameter_f(&self, sampler: GLuint, pname: GLenum, param: GLfloat) {
+            Gl::sampler_parameter_f(self.gl(), sampler, pname, param)
+        }
+
+        fn sampler_parameter_iv(&self, sampler: GLuint, pname: GLenum, params: &[GLint]) {
+            Gl::sampler_parameter_iv(self.gl(), sampler, pname, params)
+        }
+
+        fn sampler_parameter_fv(&self, sampler: GLuint, pname: GLenum, params: &[GLfloat]) {
+            Gl::sampler_parameter_fv(self.gl(), sampler, pname, params)
+        }
+
+        fn sampler_border_color_f(&self, sampler: GLuint, color: [GLfloat; 4]) {
+            Gl::sampler_border_color_f(self.gl(), sampler, color)
+        }
+
+        fn sampler_border_color_i(&self, sampler: GLuint, color: [GLint; 4]) {
+            Gl::sampler_border_color_i(self.gl(), sampler, color)
+        }
+
+        fn gen_transform_feedbacks(&self) -> u32 {
+            Gl::gen_transform_feedbacks(self.gl())
+        }
+
+        fn delete_transform_feedbacks(&self, id: GLuint) {
+            Gl::delete_transform_feedbacks(self.gl(), id)
+        }
+
+        fn is_transform_feedback(&self, id: GLuint) -> bool {
+            Gl::is_transform_feedback(self.gl(), id)
+        }
+
+        fn bind_transform_feedback(&self, target: GLenum, id: u32) {
+            Gl::bind_transform_feedback(self.gl(), target, id)
+        }
+
+        fn begin_transform_feedback(&self, mode: GLenum) {
+            Gl::begin_transform_feedback(self.gl(), mode)
+        }
+
+        fn end_transform_feedback(&self) {
+            Gl::end_transform_feedback(self.gl())
+        }
+
+        fn pause_transform_feedback(&self) {
+            Gl::pause_transform_feedback(self.gl())
         }
 
-        pub fn get_transform_feedback_varying(&self, program: GLuint, index: GLuint) -> (i32, u32, String) {
-            let mut length = 0;
-            let buf_size = 128;
-            let mut name = vec![0 as c_char; buf_size as usize];
-            let mut size = 0;
-            let mut ty = 0;
-            match self {
-                Gl::Gl(gl) => unsafe { gl.GetTransformFeedbackVarying(program, index, buf_size, &mut length, &mut size, &mut ty, name.as_mut_ptr()) },
-                Gl::Gles(gles) => unsafe { gles.GetTransformFeedbackVarying(program, index, buf_size, &mut length, &mut size, &mut ty, name.as_mut_ptr()) },
-            }
-            let name: &[u8] = unsafe { std::slice::from_raw_parts(name.as_ptr() as _, length as usize) };
-            let name = String::from_utf8(name.to_vec()).unwrap();
-            (size, ty, name)
+        fn resume_transform_feedback(&self) {
+            Gl::resume_transform_feedback(self.gl())
         }
 
-        pub fn transform_feedback_varyings(&self, program: GLuint, varyings: &[String], buffer_mode: GLenum) {
-            let c_varyings = varyings
-                .iter()
-                .map(|varying| {
-                    std::ffi::CString::new("_u".to_owned() + varying.as_str()).unwrap()
-                })
-                .collect::<Vec<_>>();
-            let pointers: Vec<*const c_char> =
-                c_varyings.iter().map(|p| p.as_ptr()).collect();
-            match self {
-                Gl::Gl(gl) => unsafe { gl.TransformFeedbackVaryings(program, varyings.len() as _, pointers.as_ptr() as _, buffer_mode) },
-                Gl::Gles(gles) => unsafe { gles.TransformFeedbackVaryings(program, varyings.len() as _, pointers.as_ptr() as _, buffer_mode) },
-            }
+        fn get_transform_feedback_varying(
+            &self,
+            program: GLuint,
+            index: GLuint,
+        ) -> (i32, u32, String) {
+            Gl::get_transform_feedback_varying(self.gl(), program, index)
         }
 
-        pub fn clear_buffer_iv(&self, buffer: GLenum, draw_buffer: GLint, value: &[GLint]) {
-            match self {
-                Gl::Gl(gl) => unsafe { gl.ClearBufferiv(buffer, draw_buffer, value.as_ptr()) },
-                Gl::Gles(gles) => unsafe { gles.ClearBufferiv(buffer, draw_buffer, value.as_ptr()) },
-            }
+        fn transform_feedback_varyings(
+            &self,
+            program: GLuint,
+            varyings: &[String],
+            buffer_mode: GLenum,
+        ) {
+            Gl::transform_feedback_varyings(self.gl(), program, varyings, buffer_mode)
         }
 
-        pub fn clear_buffer_uiv(&self, buffer: GLenum, draw_buffer: GLint, value: &[GLuint]) {
-            match self {
-                Gl::Gl(gl) => unsafe { gl.ClearBufferuiv(buffer, draw_buffer, value.as_ptr()) },
-                Gl::Gles(gles) => unsafe { gles.ClearBufferuiv(buffer, draw_buffer, value.as_ptr()) },
-            }
+        fn clear_buffer_iv(&self, buffer: GLenum, draw_buffer: GLint, value: &[GLint]) {
+            Gl::clear_buffer_iv(self.gl(), buffer, draw_buffer, value)
         }
 
-        pub fn clear_buffer_fv(&self, buffer: GLenum, draw_buffer: GLint, value: &[GLfloat]) {
-            match self {
-                Gl::Gl(gl) => unsafe { gl.ClearBufferfv(buffer, draw_buffer, value.as_ptr()) },
-                Gl::Gles(gles) => unsafe { gles.ClearBufferfv(buffer, draw_buffer, value.as_ptr()) },
-            }
+        fn clear_buffer_uiv(&self, buffer: GLenum, draw_buffer: GLint, value: &[GLuint]) {
+            Gl::clear_buffer_uiv(self.gl(), buffer, draw_buffer, value)
         }
 
-        pub fn clear_buffer_fi(
+        fn clear_buffer_fv(&self, buffer: GLenum, draw_buffer: GLint, value: &[GLfloat]) {
+            Gl::clear_buffer_fv(self.gl(), buffer, draw_buffer, value)
+        }
+
+        fn clear_buffer_fi(
             &self,
             buffer: GLenum,
             draw_buffer: GLint,
             depth: GLfloat,
             stencil: GLint,
         ) {
-            match self {
-                Gl::Gl(gl) => unsafe { gl.ClearBufferfi(buffer, draw_buffer, depth, stencil) },
-                Gl::Gles(gles) => unsafe { gles.ClearBufferfi(buffer, draw_buffer, depth, stencil) },
+            Gl::clear_buffer_fi(self.gl(), buffer, draw_buffer, depth, stencil)
+        }
+    }
+
+    impl GlApi for Gl {
+        fn gl(&self) -> &Gl {
+            self
+        }
+    }
+
+    /// A mapped range of a buffer object, as returned by
+    /// `Gl::map_buffer_range_mut`. The buffer is unmapped when this is dropped,
+    /// so the buffer must remain bound to the same target until then.
+    pub struct MappedBuffer<'a> {
+        gl: &'a Gl,
+        target: GLenum,
+        ptr: *mut u8,
+        len: usize,
+    }
+
+    impl<'a> Deref for MappedBuffer<'a> {
+        type Target = [u8];
+
+        fn deref(&self) -> &[u8] {
+            unsafe { slice::from_raw_parts(self.ptr, self.len) }
+        }
+    }
+
+    impl<'a> DerefMut for MappedBuffer<'a> {
+        fn deref_mut(&mut self) -> &mut [u8] {
+            unsafe { slice::from_raw_parts_mut(self.ptr, self.len) }
+        }
+    }
+
+    impl<'a> Drop for MappedBuffer<'a> {
+        fn drop(&mut self) {
+            self.gl.unmap_buffer(self.target);
+        }
+    }
+
+    /// Captures transform feedback into a buffer for as long as it is alive.
+    /// Creating it binds `buffer` to TRANSFORM_FEEDBACK_BUFFER at `index` and
+    /// begins transform feedback, optionally enabling RASTERIZER_DISCARD for
+    /// capture-only passes; dropping it ends transform feedback and restores
+    /// the previous RASTERIZER_DISCARD state.
+    pub struct TransformFeedbackSession<'a> {
+        gl: &'a Gl,
+        rasterizer_discard: bool,
+    }
+
+    impl<'a> TransformFeedbackSession<'a> {
+        pub fn new(
+            gl: &'a Gl,
+            index: GLuint,
+            buffer: GLuint,
+            primitive_mode: GLenum,
+            discard_rasterizer: bool,
+        ) -> TransformFeedbackSession<'a> {
+            let rasterizer_discard = gl.is_rasterizer_discard_enabled();
+            gl.bind_buffer_base(ffi::TRANSFORM_FEEDBACK_BUFFER, index, buffer);
+            if discard_rasterizer {
+                gl.set_rasterizer_discard(true);
+            }
+            gl.begin_transform_feedback(primitive_mode);
+            TransformFeedbackSession {
+                gl,
+                rasterizer_discard,
+            }
+        }
+    }
+
+    impl<'a> Drop for TransformFeedbackSession<'a> {
+        fn drop(&mut self) {
+            self.gl.end_transform_feedback();
+            self.gl.set_rasterizer_discard(self.rasterizer_discard);
+        }
+    }
+
+    type DebugCallback = RefCell<Box<dyn FnMut(GLenum, GLenum, GLuint, GLenum, &str)>>;
+
+    extern "system" fn debug_message_trampoline(
+        source: GLenum,
+        type_: GLenum,
+        id: GLuint,
+        severity: GLenum,
+        length: GLsizei,
+        message: *const GLchar,
+        user_param: *mut c_void,
+    ) {
+        let callback = unsafe { &*(user_param as *const DebugCallback) };
+        // GL calls made by the callback itself can raise messages while it is
+        // still running. Those are dropped rather than reentering it.
+        let mut callback = match callback.try_borrow_mut() {
+            Ok(callback) => callback,
+            Err(_) => return,
+        };
+        let message = unsafe {
+            if length < 0 {
+                CStr::from_ptr(message).to_bytes()
+            } else {
+                slice::from_raw_parts(message as *const u8, length as usize)
             }
+        };
+        (*callback)(
+            source,
+            type_,
+            id,
+            severity,
+            &String::from_utf8_lossy(message),
+        );
+    }
+
+    /// The capabilities the GL 3.3 and GLES 3.0 specifications guarantee for
+    /// sized internal formats, without relying on extensions.
+    fn known_format_capabilities(gl_type: GlType, internal_format: GLenum) -> FormatCaps {
+        let caps = |color_renderable, filterable| FormatCaps {
+            supported: true,
+            color_renderable,
+            filterable,
+        };
+        let desktop = gl_type == GlType::Gl;
+        match internal_format {
+            ffi::R8
+            | ffi::RG8
+            | ffi::RGB8
+            | ffi::RGB565
+            | ffi::RGBA4
+            | ffi::RGB5_A1
+            | ffi::RGBA8
+            | ffi::RGB10_A2
+            | ffi::SRGB8_ALPHA8 => caps(true, true),
+            ffi::R8_SNORM
+            | ffi::RG8_SNORM
+            | ffi::RGB8_SNORM
+            | ffi::RGBA8_SNORM
+            | ffi::SRGB8
+            | ffi::RGB9_E5 => caps(false, true),
+            ffi::R16F | ffi::RG16F | ffi::RGBA16F | ffi::R11F_G11F_B10F => caps(desktop, true),
+            ffi::RGB16F => caps(false, true),
+            ffi::R32F | ffi::RG32F | ffi::RGBA32F => caps(desktop, desktop),
+            ffi::RGB32F => caps(false, desktop),
+            ffi::R8I
+            | ffi::R8UI
+            | ffi::R16I
+            | ffi::R16UI
+            | ffi::R32I
+            | ffi::R32UI
+            | ffi::RG8I
+            | ffi::RG8UI
+            | ffi::RG16I
+            | ffi::RG16UI
+            | ffi::RG32I
+            | ffi::RG32UI
+            | ffi::RGBA8I
+            | ffi::RGBA8UI
+            | ffi::RGBA16I
+            | ffi::RGBA16UI
+            | ffi::RGBA32I
+            | ffi::RGBA32UI
+            | ffi::RGB10_A2UI => caps(true, false),
+            ffi::RGB8I | ffi::RGB8UI | ffi::RGB16I | ffi::RGB16UI | ffi::RGB32I | ffi::RGB32UI => {
+                caps(false, false)
+            },
+            ffi::DEPTH_COMPONENT16
+            | ffi::DEPTH_COMPONENT24
+            | ffi::DEPTH_COMPONENT32F
+            | ffi::DEPTH24_STENCIL8
+            | ffi::DEPTH32F_STENCIL8 => caps(false, false),
+            _ => FormatCaps::default(),
+        }
+    }
+
+    fn framebuffer_status_name(status: GLenum) -> &'static str {
+        match status {
+            ffi::FRAMEBUFFER_COMPLETE => "FRAMEBUFFER_COMPLETE",
+            ffi::FRAMEBUFFER_UNDEFINED => "FRAMEBUFFER_UNDEFINED",
+            ffi::FRAMEBUFFER_INCOMPLETE_ATTACHMENT => "FRAMEBUFFER_INCOMPLETE_ATTACHMENT",
+            ffi::FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT => {
+                "FRAMEBUFFER_INCOMPLETE_MISSING_ATTACHMENT"
+            },
+            ffi::FRAMEBUFFER_INCOMPLETE_DIMENSIONS => "FRAMEBUFFER_INCOMPLETE_DIMENSIONS",
+            ffi::FRAMEBUFFER_INCOMPLETE_DRAW_BUFFER => "FRAMEBUFFER_INCOMPLETE_DRAW_BUFFER",
+            ffi::FRAMEBUFFER_INCOMPLETE_READ_BUFFER => "FRAMEBUFFER_INCOMPLETE_READ_BUFFER",
+            ffi::FRAMEBUFFER_UNSUPPORTED => "FRAMEBUFFER_UNSUPPORTED",
+            ffi::FRAMEBUFFER_INCOMPLETE_MULTISAMPLE => "FRAMEBUFFER_INCOMPLETE_MULTISAMPLE",
+            ffi::FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS => "FRAMEBUFFER_INCOMPLETE_LAYER_TARGETS",
+            _ => "unknown framebuffer status",
         }
     }
 
@@ -2639,29 +10852,145 @@ pub mod gl {
         format: GLenum,
         pixel_type: GLenum,
     ) -> usize {
+        match try_calculate_length(width, height, format, pixel_type) {
+            Ok(len) => len,
+            Err(UnsupportedPixelFormat::Format(format)) => {
+                panic!("unsupported format: {:?}", format)
+            },
+            Err(UnsupportedPixelFormat::PixelType(pixel_type)) => {
+                panic!("unsupported pixel_type: {:?}", pixel_type)
+            },
+            Err(UnsupportedPixelFormat::Dimensions(width, height)) => {
+                panic!("unsupported dimensions: {}x{}", width, height)
+            },
+        }
+    }
+
+    /// Clamps a requested anisotropic filtering level to `[1, max]`, or
+    /// returns `None` when `max` allows no anisotropy at all.
+    fn clamp_anisotropy(value: GLfloat, max: GLfloat) -> Option<GLfloat> {
+        if max > 1. {
+            Some(value.max(1.).min(max))
+        } else {
+            None
+        }
+    }
+
+    /// The number of bytes `read_pixels` and friends produce for a
+    /// `width` by `height` image, with no padding between rows.
+    pub fn try_calculate_length(
+        width: GLsizei,
+        height: GLsizei,
+        format: GLenum,
+        pixel_type: GLenum,
+    ) -> Result<usize, UnsupportedPixelFormat> {
         let colors = match format {
             ffi::RED => 1,
+            ffi::RG => 2,
             ffi::RGB => 3,
             ffi::BGR => 3,
 
             ffi::RGBA => 4,
             ffi::BGRA => 4,
 
+            ffi::RED_INTEGER => 1,
+            ffi::RG_INTEGER => 2,
+            ffi::RGB_INTEGER => 3,
+            ffi::RGBA_INTEGER => 4,
+
             ffi::ALPHA => 1,
             ffi::R16 => 1,
             ffi::LUMINANCE => 1,
+            ffi::LUMINANCE_ALPHA => 2,
             ffi::DEPTH_COMPONENT => 1,
-            _ => panic!("unsupported format: {:?}", format),
+            ffi::DEPTH_STENCIL => 1,
+            ffi::STENCIL_INDEX => 1,
+            _ => return Err(UnsupportedPixelFormat::Format(format)),
+        };
+        // Packed types hold every component of a pixel in a single value.
+        let packed_size = match pixel_type {
+            ffi::UNSIGNED_SHORT_5_6_5 => Some(2),
+            ffi::UNSIGNED_SHORT_4_4_4_4 => Some(2),
+            ffi::UNSIGNED_SHORT_5_5_5_1 => Some(2),
+            ffi::UNSIGNED_INT_2_10_10_10_REV => Some(4),
+            ffi::UNSIGNED_INT_10F_11F_11F_REV => Some(4),
+            ffi::UNSIGNED_INT_5_9_9_9_REV => Some(4),
+            ffi::UNSIGNED_INT_24_8 => Some(4),
+            ffi::FLOAT_32_UNSIGNED_INT_24_8_REV => Some(8),
+            _ => None,
+        };
+        let pixel_size = match packed_size {
+            Some(size) => size,
+            None => colors * pixel_depth(pixel_type)?,
+        };
+
+        let invalid = UnsupportedPixelFormat::Dimensions(width, height);
+        let (w, h) = match (usize::try_from(width), usize::try_from(height)) {
+            (Ok(w), Ok(h)) => (w, h),
+            _ => return Err(invalid),
         };
+        w.checked_mul(h)
+            .and_then(|pixels| pixels.checked_mul(pixel_size))
+            .ok_or(invalid)
+    }
+
+    /// The size of a single component of an unpacked pixel type.
+    fn pixel_depth(pixel_type: GLenum) -> Result<usize, UnsupportedPixelFormat> {
         let depth = match pixel_type {
             ffi::UNSIGNED_BYTE => 1,
+            ffi::BYTE => 1,
             ffi::UNSIGNED_SHORT => 2,
             ffi::SHORT => 2,
+            ffi::HALF_FLOAT => 2,
+            ffi::UNSIGNED_INT => 4,
+            ffi::INT => 4,
             ffi::FLOAT => 4,
-            _ => panic!("unsupported pixel_type: {:?}", pixel_type),
+            _ => return Err(UnsupportedPixelFormat::PixelType(pixel_type)),
         };
+        Ok(depth)
+    }
 
-        (width * height * colors * depth) as usize
+    /// Copies tightly-packed `pixels` for `upload_texture_2d_with_options`,
+    /// flipping the rows and/or premultiplying the color by alpha.
+    fn apply_unpack_options(
+        width: GLsizei,
+        height: GLsizei,
+        format: GLenum,
+        ty: GLenum,
+        pixels: &[u8],
+        flip_y: bool,
+        premultiply: bool,
+    ) -> Vec<u8> {
+        assert_eq!(calculate_length(width, height, format, ty), pixels.len());
+        let mut data = pixels.to_vec();
+        if flip_y {
+            let stride = calculate_length(width, 1, format, ty);
+            data.clear();
+            for row in pixels.chunks(stride.max(1)).rev() {
+                data.extend_from_slice(row);
+            }
+        }
+        if premultiply {
+            let (components, alpha) = match format {
+                ffi::RGBA | ffi::BGRA => (4, 3),
+                ffi::LUMINANCE_ALPHA => (2, 1),
+                _ => return data,
+            };
+            assert_eq!(
+                ty,
+                ffi::UNSIGNED_BYTE,
+                "premultiplication requires UNSIGNED_BYTE"
+            );
+            for pixel in data.chunks_mut(components) {
+                let a = pixel[alpha] as u32;
+                for (i, c) in pixel.iter_mut().enumerate() {
+                    if i != alpha {
+                        *c = ((*c as u32 * a + 127) / 255) as u8;
+                    }
+                }
+            }
+        }
+        data
     }
 
     pub fn buffer_data<T>(gl_: &Gl, target: GLenum, data: &[T], usage: GLenum) {
@@ -2675,6 +11004,57 @@ pub mod gl {
         }
     }
 
+    pub fn buffer_data_typed<T>(gl_: &Gl, target: BufferTarget, data: &[T], usage: BufferUsage) {
+        buffer_data(gl_, target.to_gl(), data, usage.to_gl())
+    }
+
+    /// Like `buffer_data`, but reports any error raised by the upload, such as
+    /// OUT_OF_MEMORY for an allocation the driver couldn't satisfy. Errors
+    /// already pending are discarded first so they aren't attributed to it.
+    pub fn try_buffer_data<T>(
+        gl_: &Gl,
+        target: GLenum,
+        data: &[T],
+        usage: GLenum,
+    ) -> Result<(), GlError> {
+        loop {
+            match gl_.get_error() {
+                ffi::NO_ERROR | ffi::CONTEXT_LOST => break,
+                _ => {},
+            }
+        }
+        buffer_data(gl_, target, data, usage);
+        match GlError::from_gl(gl_.get_error()) {
+            GlError::NoError => Ok(()),
+            error => Err(error),
+        }
+    }
+
+    /// Integer types that can be used as element indices.
+    pub trait IndexType: Copy {
+        /// The type enum to pass to `draw_elements` for indices of this type.
+        const GL_TYPE: GLenum;
+    }
+
+    impl IndexType for u8 {
+        const GL_TYPE: GLenum = ffi::UNSIGNED_BYTE;
+    }
+
+    impl IndexType for u16 {
+        const GL_TYPE: GLenum = ffi::UNSIGNED_SHORT;
+    }
+
+    impl IndexType for u32 {
+        const GL_TYPE: GLenum = ffi::UNSIGNED_INT;
+    }
+
+    /// Uploads `indices` to the bound ELEMENT_ARRAY_BUFFER and returns the
+    /// element type to draw them with.
+    pub fn upload_indices<T: IndexType>(gl_: &Gl, indices: &[T]) -> GLenum {
+        buffer_data(gl_, ffi::ELEMENT_ARRAY_BUFFER, indices, ffi::STATIC_DRAW);
+        T::GL_TYPE
+    }
+
     pub fn buffer_sub_data<T>(gl_: &Gl, target: GLenum, offset: isize, data: &[T]) {
         unsafe {
             gl_.buffer_sub_data(
@@ -2686,10 +11066,615 @@ pub mod gl {
         }
     }
 
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn calculate_length_by_format_and_type() {
+            let cases = [
+                (RED, UNSIGNED_BYTE, 1),
+                (RG, UNSIGNED_BYTE, 2),
+                (RGB, UNSIGNED_BYTE, 3),
+                (BGR, UNSIGNED_BYTE, 3),
+                (RGBA, UNSIGNED_BYTE, 4),
+                (BGRA, UNSIGNED_BYTE, 4),
+                (RED_INTEGER, INT, 4),
+                (RG_INTEGER, UNSIGNED_SHORT, 4),
+                (RGB_INTEGER, BYTE, 3),
+                (RGBA_INTEGER, UNSIGNED_INT, 16),
+                (ALPHA, UNSIGNED_BYTE, 1),
+                (R16, UNSIGNED_SHORT, 2),
+                (LUMINANCE, UNSIGNED_BYTE, 1),
+                (LUMINANCE_ALPHA, UNSIGNED_BYTE, 2),
+                (DEPTH_COMPONENT, UNSIGNED_SHORT, 2),
+                (DEPTH_COMPONENT, FLOAT, 4),
+                (STENCIL_INDEX, UNSIGNED_BYTE, 1),
+                (RGBA, SHORT, 8),
+                (RGBA, HALF_FLOAT, 8),
+                (RGBA, FLOAT, 16),
+                (RGB, UNSIGNED_SHORT_5_6_5, 2),
+                (RGBA, UNSIGNED_SHORT_4_4_4_4, 2),
+                (RGBA, UNSIGNED_SHORT_5_5_5_1, 2),
+                (RGBA, UNSIGNED_INT_2_10_10_10_REV, 4),
+                (RGB, UNSIGNED_INT_10F_11F_11F_REV, 4),
+                (RGB, UNSIGNED_INT_5_9_9_9_REV, 4),
+                (DEPTH_STENCIL, UNSIGNED_INT_24_8, 4),
+                (DEPTH_STENCIL, FLOAT_32_UNSIGNED_INT_24_8_REV, 8),
+            ];
+            for &(format, pixel_type, pixel_size) in cases.iter() {
+                assert_eq!(
+                    try_calculate_length(3, 5, format, pixel_type),
+                    Ok(3 * 5 * pixel_size),
+                    "format {:#x}, type {:#x}",
+                    format,
+                    pixel_type,
+                );
+            }
+            assert_eq!(
+                try_calculate_length(1, 1, RGBA, 0),
+                Err(UnsupportedPixelFormat::PixelType(0))
+            );
+            assert_eq!(
+                try_calculate_length(1, 1, 0, UNSIGNED_BYTE),
+                Err(UnsupportedPixelFormat::Format(0))
+            );
+        }
+
+        #[test]
+        fn calculate_length_rejects_bad_dimensions() {
+            assert_eq!(try_calculate_length(0, 7, RGBA, UNSIGNED_BYTE), Ok(0));
+            assert_eq!(
+                try_calculate_length(-1, 1, RGBA, UNSIGNED_BYTE),
+                Err(UnsupportedPixelFormat::Dimensions(-1, 1))
+            );
+            assert_eq!(
+                try_calculate_length(1, -1, RGBA, UNSIGNED_BYTE),
+                Err(UnsupportedPixelFormat::Dimensions(1, -1))
+            );
+            // Overflows usize on both 32-bit and 64-bit targets.
+            let max = GLsizei::MAX;
+            assert_eq!(
+                try_calculate_length(max, max, RGBA, FLOAT),
+                Err(UnsupportedPixelFormat::Dimensions(max, max))
+            );
+            assert_eq!(
+                try_calculate_length(max, 1, RED, UNSIGNED_BYTE),
+                Ok(max as usize)
+            );
+        }
+
+        #[test]
+        fn try_read_pixels_reports_unsupported_formats() {
+            let gl = Gl::mock_fns();
+            assert_eq!(
+                gl.try_read_pixels(0, 0, 2, 3, RGBA, UNSIGNED_BYTE),
+                Ok(vec![0; 24])
+            );
+            assert_eq!(
+                gl.try_read_pixels(0, 0, 2, 3, RGBA, 0),
+                Err(UnsupportedPixelFormat::PixelType(0))
+            );
+        }
+
+        #[test]
+        fn extensions_from_the_extension_string() {
+            let gl = Gl::mock_fns();
+            gl.set_mock_string(EXTENSIONS, "GL_KHR_debug  GL_OES_EGL_image");
+            assert_eq!(gl.extensions().len(), 2);
+            assert!(gl.has_extension("GL_KHR_debug"));
+            assert!(!gl.has_extension("GL_KHR"));
+        }
+
+        #[test]
+        fn debug_message_trampoline_forwards_messages() {
+            let messages = Rc::new(RefCell::new(vec![]));
+            let sink = messages.clone();
+            let callback: DebugCallback = RefCell::new(Box::new(
+                move |source, type_, id, severity, message: &str| {
+                    sink.borrow_mut()
+                        .push((source, type_, id, severity, message.to_owned()));
+                },
+            ));
+            let user_param = &callback as *const DebugCallback as *mut c_void;
+            let deliver = |length: GLsizei, message: &[u8]| {
+                debug_message_trampoline(
+                    DEBUG_SOURCE_APPLICATION,
+                    DEBUG_TYPE_MARKER,
+                    7,
+                    DEBUG_SEVERITY_NOTIFICATION,
+                    length,
+                    message.as_ptr() as *const GLchar,
+                    user_param,
+                )
+            };
+            deliver(5, b"hello, world");
+            deliver(-1, b"goodbye\0");
+            // Messages raised while the callback is running are dropped.
+            let running = callback.borrow_mut();
+            deliver(-1, b"reentrant\0");
+            drop(running);
+
+            let expected = |message: &str| {
+                (
+                    DEBUG_SOURCE_APPLICATION,
+                    DEBUG_TYPE_MARKER,
+                    7,
+                    DEBUG_SEVERITY_NOTIFICATION,
+                    message.to_owned(),
+                )
+            };
+            assert_eq!(
+                *messages.borrow(),
+                vec![expected("hello"), expected("goodbye")]
+            );
+        }
+
+        #[test]
+        fn anisotropy_is_clamped_to_the_maximum() {
+            assert_eq!(clamp_anisotropy(8., 16.), Some(8.));
+            assert_eq!(clamp_anisotropy(8., 4.), Some(4.));
+            assert_eq!(clamp_anisotropy(0.5, 4.), Some(1.));
+            assert_eq!(clamp_anisotropy(8., 1.), None);
+        }
+
+        #[test]
+        fn gen_into_matches_gen() {
+            let vec_gl = Gl::mock_fns();
+            let slice_gl = Gl::mock_fns();
+            let mut ids = [0; 3];
+            slice_gl.gen_textures_into(&mut ids);
+            assert_eq!(vec_gl.gen_textures(3), ids);
+            slice_gl.gen_buffers_into(&mut ids);
+            assert_eq!(vec_gl.gen_buffers(3), ids);
+            slice_gl.gen_framebuffers_into(&mut ids[..0]);
+            assert!(vec_gl.gen_framebuffers(0).is_empty());
+
+            let gl = Gl::recording_fns();
+            gl.gen_textures(1);
+            gl.gen_textures_into(&mut [0]);
+            assert_eq!(
+                gl.take_log(),
+                vec![
+                    GlCall::Other("gen_textures"),
+                    GlCall::Other("gen_textures_into"),
+                ]
+            );
+        }
+
+        #[test]
+        fn drain_errors_returns_every_pending_error() {
+            let gl = Gl::mock_fns();
+            assert_eq!(gl.get_error_typed(), GlError::NoError);
+            gl.push_mock_error(INVALID_ENUM);
+            assert_eq!(gl.get_error_typed(), GlError::InvalidEnum);
+
+            gl.push_mock_error(INVALID_VALUE);
+            gl.push_mock_error(OUT_OF_MEMORY);
+            assert_eq!(
+                gl.drain_errors(),
+                vec![GlError::InvalidValue, GlError::OutOfMemory]
+            );
+            assert!(gl.drain_errors().is_empty());
+
+            // A lost context would report CONTEXT_LOST forever.
+            gl.push_mock_error(CONTEXT_LOST);
+            gl.push_mock_error(INVALID_OPERATION);
+            assert_eq!(gl.drain_errors(), vec![GlError::ContextLost]);
+        }
+
+        #[cfg(debug_assertions)]
+        #[test]
+        #[should_panic(expected = "upload raised [InvalidEnum, InvalidValue]")]
+        fn assert_no_error_reports_every_error() {
+            let gl = Gl::mock_fns();
+            gl.push_mock_error(INVALID_ENUM);
+            gl.push_mock_error(INVALID_VALUE);
+            gl.assert_no_error("upload");
+        }
+
+        #[test]
+        fn gl_checked_passes_through_the_result() {
+            let gl = Gl::mock_fns();
+            assert_eq!(
+                crate::gl_checked!(gl, "gen", gl.gen_textures(2)),
+                vec![1, 2]
+            );
+        }
+
+        #[cfg(debug_assertions)]
+        #[test]
+        #[should_panic(expected = "gen raised [OutOfMemory]")]
+        fn gl_checked_panics_on_errors() {
+            let gl = Gl::mock_fns();
+            crate::gl_checked!(gl, "gen", gl.push_mock_error(OUT_OF_MEMORY));
+        }
+
+        #[test]
+        fn load_gles_without_entry_points() {
+            let gl = Gl::load_with(GlType::Gles, |_| ptr::null());
+            assert_eq!(gl.get_type(), GlType::Gles);
+            // Entry points that are checked with is_loaded are skipped.
+            gl.framebuffer_texture(FRAMEBUFFER, COLOR_ATTACHMENT0, 1, 0);
+        }
+
+        #[test]
+        #[cfg(feature = "gles-only")]
+        #[should_panic(expected = "sparkle was built with the gles-only feature")]
+        fn load_desktop_gl_with_gles_only() {
+            Gl::load_with(GlType::Gl, |_| ptr::null());
+        }
+
+        #[test]
+        fn get_version_parses_desktop_and_es_strings() {
+            let gl = Gl::mock_fns();
+            let cases = [
+                ("4.6.0 NVIDIA 535.54.03", (4, 6)),
+                ("3.3 (Core Profile) Mesa 23.1.0", (3, 3)),
+                ("OpenGL ES 3.2 Mesa 23.1.0", (3, 2)),
+                ("OpenGL ES 3.0 build 1.13@5776728", (3, 0)),
+                ("not a version", (0, 0)),
+                ("", (0, 0)),
+            ];
+            for &(version, expected) in cases.iter() {
+                gl.set_mock_string(VERSION, version);
+                assert_eq!(gl.get_version(), expected, "{:?}", version);
+            }
+        }
+
+        #[test]
+        fn read_pixels_with_stride_restores_pack_state() {
+            let gl = Gl::recording_fns();
+            // Two rows of 3 RGBA pixels, 5 pixels apart; the last row isn't padded.
+            let mut buffer = vec![0; (5 + 3) * 4];
+            gl.read_pixels_into_buffer_with_stride(0, 0, 3, 2, RGBA, UNSIGNED_BYTE, 5, &mut buffer);
+            let pixel_store: Vec<_> = gl
+                .take_log()
+                .into_iter()
+                .filter(|call| matches!(call, GlCall::PixelStoreI(..)))
+                .collect();
+            // A recording context reports zero for the previous values.
+            assert_eq!(
+                pixel_store,
+                vec![
+                    GlCall::PixelStoreI(PACK_ALIGNMENT, 1),
+                    GlCall::PixelStoreI(PACK_ROW_LENGTH, 5),
+                    GlCall::PixelStoreI(PACK_ROW_LENGTH, 0),
+                    GlCall::PixelStoreI(PACK_ALIGNMENT, 0),
+                ]
+            );
+        }
+
+        #[test]
+        #[should_panic]
+        fn read_pixels_with_stride_checks_the_buffer_length() {
+            let gl = Gl::mock_fns();
+            let mut buffer = vec![0; (5 + 3) * 4 - 1];
+            gl.read_pixels_into_buffer_with_stride(0, 0, 3, 2, RGBA, UNSIGNED_BYTE, 5, &mut buffer);
+        }
+
+        #[test]
+        fn recording_logs_calls_in_order() {
+            let gl = Gl::recording_fns();
+            gl.clear(COLOR_BUFFER_BIT);
+            gl.draw_arrays(TRIANGLES, 0, 3);
+            assert_eq!(
+                gl.take_log(),
+                vec![
+                    GlCall::Clear(COLOR_BUFFER_BIT),
+                    GlCall::DrawArrays(TRIANGLES, 0, 3),
+                ]
+            );
+            assert!(gl.take_log().is_empty());
+        }
+
+        #[test]
+        fn mock_ids_are_unique_and_non_zero() {
+            let gl = Gl::mock_fns();
+            let mut ids = gl.gen_textures(4);
+            ids.extend(gl.gen_buffers(2));
+            ids.push(gl.create_program());
+            assert!(!ids.contains(&0));
+            let mut sorted = ids.clone();
+            sorted.sort_unstable();
+            sorted.dedup();
+            assert_eq!(sorted.len(), ids.len());
+        }
+
+        #[test]
+        fn buffer_enums_round_trip() {
+            let targets = [
+                BufferTarget::Array,
+                BufferTarget::ElementArray,
+                BufferTarget::CopyRead,
+                BufferTarget::CopyWrite,
+                BufferTarget::PixelPack,
+                BufferTarget::PixelUnpack,
+                BufferTarget::TransformFeedback,
+                BufferTarget::Uniform,
+                BufferTarget::DrawIndirect,
+            ];
+            for &target in targets.iter() {
+                assert_eq!(BufferTarget::from_gl(target.to_gl()), Some(target));
+                assert_eq!(GLenum::from(target), target.to_gl());
+            }
+            assert_eq!(BufferTarget::from_gl(TEXTURE_2D), None);
+
+            let usages = [
+                BufferUsage::StreamDraw,
+                BufferUsage::StreamRead,
+                BufferUsage::StreamCopy,
+                BufferUsage::StaticDraw,
+                BufferUsage::StaticRead,
+                BufferUsage::StaticCopy,
+                BufferUsage::DynamicDraw,
+                BufferUsage::DynamicRead,
+                BufferUsage::DynamicCopy,
+            ];
+            for &usage in usages.iter() {
+                assert_eq!(BufferUsage::from_gl(usage.to_gl()), Some(usage));
+                assert_eq!(GLenum::from(usage), usage.to_gl());
+            }
+            assert_eq!(BufferUsage::from_gl(ARRAY_BUFFER), None);
+        }
+
+        #[test]
+        fn gl_api_accepts_a_test_double() {
+            /// Reports a fixed renderer and records everything else.
+            struct FixedRenderer(Rc<Gl>);
+
+            impl GlApi for FixedRenderer {
+                fn gl(&self) -> &Gl {
+                    &self.0
+                }
+
+                fn get_string(&self, which: GLenum) -> String {
+                    match which {
+                        RENDERER => "Test Double".to_owned(),
+                        _ => String::new(),
+                    }
+                }
+            }
+
+            fn clear_unless_software(gl: &dyn GlApi) -> bool {
+                if gl.get_string(RENDERER).contains("llvmpipe") {
+                    return false;
+                }
+                gl.clear(COLOR_BUFFER_BIT);
+                true
+            }
+
+            let double = FixedRenderer(Gl::recording_fns());
+            assert!(clear_unless_software(&double));
+            assert_eq!(double.take_log(), vec![GlCall::Clear(COLOR_BUFFER_BIT)]);
+
+            let mock = Gl::mock_fns();
+            mock.set_mock_string(RENDERER, "llvmpipe (LLVM 15.0.7, 256 bits)");
+            assert!(!clear_unless_software(&*mock));
+        }
+
+        #[test]
+        fn debug_output_names_the_backend() {
+            let gles = Gl::load_with(GlType::Gles, |_| ptr::null());
+            assert_eq!(format!("{:?}", gles), "Gles");
+            assert_eq!(format!("{:?}", Gl::mock_fns()), "Mock");
+            assert_eq!(format!("{:?}", Gl::recording_fns()), "Recording");
+            assert_eq!(
+                format!("{:?}", Gl::debug_fns(gles, true)),
+                "Debug(Gles, true)"
+            );
+        }
+
+        #[test]
+        #[cfg(not(feature = "gles-only"))]
+        fn debug_output_names_desktop_gl() {
+            let gl = Gl::load_with(GlType::Gl, |_| ptr::null());
+            assert_eq!(format!("{:?}", gl), "Gl");
+        }
+
+        #[test]
+        #[should_panic(expected = "gen_textures_into raised")]
+        fn debug_errors_name_the_public_gen_method() {
+            let mock = Gl::mock_fns();
+            let gl = Gl::debug_fns(mock.clone(), true);
+            mock.push_mock_error(OUT_OF_MEMORY);
+            gl.gen_textures_into(&mut [0; 2]);
+        }
+
+        #[test]
+        #[should_panic(expected = "bind_texture raised InvalidEnum")]
+        fn debug_context_panics_with_the_method_name() {
+            let mock = Gl::mock_fns();
+            let gl = Gl::debug_fns(mock.clone(), true);
+            gl.bind_texture(TEXTURE_2D, 1);
+            mock.push_mock_error(INVALID_ENUM);
+            gl.bind_texture(TEXTURE_2D, 2);
+        }
+
+        #[test]
+        fn debug_context_without_panics_consumes_the_error() {
+            let mock = Gl::mock_fns();
+            let gl = Gl::debug_fns(mock.clone(), false);
+            mock.push_mock_error(INVALID_ENUM);
+            gl.bind_texture(TEXTURE_2D, 1);
+            assert_eq!(mock.get_error(), NO_ERROR);
+        }
+
+        thread_local! {
+            static FAKE_DEBUG_CALLBACK: Cell<(GLDEBUGPROC, *const c_void)> =
+                Cell::new((None, ptr::null()));
+        }
+
+        extern "system" fn fake_enable(_: GLenum) {}
+
+        extern "system" fn fake_debug_message_callback(
+            callback: GLDEBUGPROC,
+            user_param: *const c_void,
+        ) {
+            FAKE_DEBUG_CALLBACK.with(|registered| registered.set((callback, user_param)));
+        }
+
+        extern "system" fn fake_debug_message_insert(
+            source: GLenum,
+            type_: GLenum,
+            id: GLuint,
+            severity: GLenum,
+            length: GLsizei,
+            buf: *const GLchar,
+        ) {
+            if let (Some(callback), user_param) = FAKE_DEBUG_CALLBACK.with(Cell::get) {
+                callback(
+                    source,
+                    type_,
+                    id,
+                    severity,
+                    length,
+                    buf,
+                    user_param as *mut c_void,
+                );
+            }
+        }
+
+        /// Loads a GLES context whose only entry points are enough to deliver
+        /// inserted debug messages to the registered callback.
+        fn load_fake_debug_context() -> Rc<Gl> {
+            Gl::load_with(GlType::Gles, |name| match name {
+                "glEnable" => fake_enable as *const c_void,
+                "glDebugMessageCallback" => fake_debug_message_callback as *const c_void,
+                "glDebugMessageInsert" => fake_debug_message_insert as *const c_void,
+                _ => ptr::null(),
+            })
+        }
+
+        #[test]
+        fn debug_message_callback_receives_inserted_messages() {
+            let gl = load_fake_debug_context();
+            let messages = Rc::new(RefCell::new(vec![]));
+            let sink = messages.clone();
+            gl.debug_message_callback(move |_, _, id, _, message| {
+                sink.borrow_mut().push((id, message.to_owned()));
+            });
+            gl.debug_message_insert(
+                DEBUG_SOURCE_APPLICATION,
+                DEBUG_TYPE_MARKER,
+                3,
+                DEBUG_SEVERITY_NOTIFICATION,
+                "frame start",
+            );
+            assert_eq!(*messages.borrow(), vec![(3, "frame start".to_owned())]);
+
+            // Dropping the context unregisters the callback before freeing it.
+            drop(gl);
+            assert!(FAKE_DEBUG_CALLBACK.with(Cell::get).0.is_none());
+            assert_eq!(Rc::strong_count(&messages), 1);
+        }
+
+        #[test]
+        fn gles_texture_readback_reports_what_it_cannot_do() {
+            let gl = Gl::load_with(GlType::Gles, |_| ptr::null());
+            assert_eq!(
+                gl.dump_texture(TEXTURE_2D, 0, RGBA, UNSIGNED_BYTE),
+                Err(TexImageError::MissingEntryPoint("glGetTexLevelParameteriv"))
+            );
+            assert_eq!(
+                gl.get_tex_image_into_buffer(TEXTURE_3D, 0, RGBA, UNSIGNED_BYTE, &mut [0; 4]),
+                Err(TexImageError::UnsupportedTarget(TEXTURE_3D))
+            );
+
+            let gl = Gl::mock_fns();
+            assert_eq!(
+                gl.dump_texture(TEXTURE_2D, 0, RGBA, UNSIGNED_BYTE),
+                Ok(vec![])
+            );
+            assert_eq!(
+                gl.dump_texture(TEXTURE_2D, 0, RGBA, 0),
+                Err(TexImageError::PixelFormat(
+                    UnsupportedPixelFormat::PixelType(0)
+                ))
+            );
+        }
+
+        #[test]
+        fn unpack_options_flip_and_premultiply() {
+            let pixels = [
+                10, 20, 30, 255, 200, 100, 50, 0, // bottom row
+                255, 255, 255, 128, 40, 80, 120, 64, // top row
+            ];
+            let unchanged = apply_unpack_options(2, 2, RGBA, UNSIGNED_BYTE, &pixels, false, false);
+            assert_eq!(unchanged, pixels);
+            assert_eq!(
+                apply_unpack_options(2, 2, RGBA, UNSIGNED_BYTE, &pixels, true, true),
+                vec![128, 128, 128, 128, 10, 20, 30, 64, 10, 20, 30, 255, 0, 0, 0, 0]
+            );
+            assert_eq!(
+                apply_unpack_options(
+                    3,
+                    1,
+                    LUMINANCE_ALPHA,
+                    UNSIGNED_BYTE,
+                    &[200, 128, 7, 0, 9, 255],
+                    false,
+                    true
+                ),
+                vec![100, 128, 0, 0, 9, 255]
+            );
+            // Formats without alpha are left as they are.
+            assert_eq!(
+                apply_unpack_options(1, 2, RGB, UNSIGNED_BYTE, &[1, 2, 3, 4, 5, 6], true, true),
+                vec![4, 5, 6, 1, 2, 3]
+            );
+        }
+
+        #[test]
+        fn upload_with_options_restores_unpack_alignment() {
+            let gl = Gl::recording_fns();
+            gl.upload_texture_2d_with_options(
+                TEXTURE_2D,
+                0,
+                RGB as GLint,
+                1,
+                1,
+                RGB,
+                UNSIGNED_BYTE,
+                &[1, 2, 3],
+                true,
+                false,
+            );
+            let pixel_store: Vec<_> = gl
+                .take_log()
+                .into_iter()
+                .filter(|call| matches!(call, GlCall::PixelStoreI(..)))
+                .collect();
+            assert_eq!(
+                pixel_store,
+                vec![
+                    GlCall::PixelStoreI(UNPACK_ALIGNMENT, 1),
+                    GlCall::PixelStoreI(UNPACK_ALIGNMENT, 0),
+                ]
+            );
+        }
+
+        #[test]
+        fn read_pixels_fast_reports_an_unknown_preferred_format() {
+            let gl = Gl::mock_fns();
+            assert_eq!(
+                gl.read_pixels_fast(0, 0, 4, 4),
+                Err(UnsupportedPixelFormat::Format(0))
+            );
+        }
+
+        #[test]
+        fn compressed_readback_is_empty_on_gles() {
+            let gl = Gl::load_with(GlType::Gles, |_| ptr::null());
+            assert!(gl.get_compressed_tex_image(TEXTURE_2D, 0).is_empty());
+        }
+    }
+
     pub mod ffi {
         include!(concat!(env!("OUT_DIR"), "/gl_and_gles_bindings.rs"));
     }
 
+    #[cfg(not(feature = "gles-only"))]
     pub mod ffi_gl {
         include!(concat!(env!("OUT_DIR"), "/gl_bindings.rs"));
     }